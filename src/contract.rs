@@ -1,25 +1,46 @@
+use std::cmp::Reverse;
 use std::collections::HashSet;
 
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    to_json_binary, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdError, StdResult,
+    to_json_binary, Binary, Coin, Decimal256, Deps, DepsMut, Empty, Env, MessageInfo, Order,
+    Response, SignedDecimal256, StdError, StdResult, Storage, Uint128, Uint256, Uint512,
 };
 use cw2::set_contract_version;
-use pyth_sdk::{Identifier, Price, PriceFeed, PriceIdentifier, UnixTimestamp};
+use cw_storage_plus::{Bound, Map};
+use pyth_sdk::{Price, PriceFeed, PriceIdentifier, UnixTimestamp};
 
 use crate::error::ContractError;
 use crate::governance::{GovernanceAction, GovernanceInstruction, GovernanceModule};
-use crate::helpers::{parse_and_verify_vaa, parse_update, verify_vaa_from_governance_source};
+use crate::helpers::{
+    normalize_price, parse_and_verify_vaa, parse_update, to_decimal256, validate_price,
+    verify_vaa_from_governance_source,
+};
 use crate::msg::{
-    ExecuteMsg, InstantiateMsg, ParsePriceFeedsResponse, ParseSinglePriceFeedResponse, QueryMsg,
+    AggregatedPriceFeed, EmaGuardMode, ExecuteMsg, GuardedPriceResponse, InstantiateMsg,
+    NormalizedPriceFeed, ParsePriceFeedsResponse, ParseSinglePriceFeedResponse,
+    PriceBoundsResponse, PriceFeedIdsResponse, PriceFeedRedirect, PriceFeedRegistryResponse,
+    PriceNormalization, QueryMsg, RegisteredPriceFeed, ResolvePriceFeedIdResponse, SkipReason,
+    SkippedPriceFeed, ValueOfResponse,
+};
+use crate::state::{
+    ConfigInfo, DenomPriceFeed, DerivedPriceFeed, PriceFeedMetadata, CONFIG, DENIED_PRICE_FEEDS,
+    DENOM_PRICE_FEEDS, DERIVED_PRICE_FEEDS, FROZEN_PRICE_FEEDS, PRICE_FEED_METADATA,
+    PRICE_FEED_REDIRECTS, PRICE_FEED_SYMBOLS,
 };
-use crate::state::{ConfigInfo, CONFIG};
 
 // version info for migration info
 const CONTRACT_NAME: &str = "crates.io:pyth_cosmwasm";
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
+// settings for pagination
+const DEFAULT_LIMIT: u32 = 10;
+const MAX_LIMIT: u32 = 30;
+
+// Price::div reduces both mantissas to at most this value before dividing.
+const MAX_DIVISION_MANTISSA: u64 = (1 << 28) - 1;
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
     deps: DepsMut,
@@ -35,6 +56,13 @@ pub fn instantiate(
         governance_source: msg.governance_source.clone(),
         governance_source_index: msg.governance_source_index,
         governance_sequence_number: msg.governance_sequence_number,
+        max_publish_time_skew: None,
+        max_conf_bps: None,
+        reject_non_positive_prices: false,
+        paused: false,
+        conf_multiplier: Decimal256::one(),
+        ema_guard_max_conf_bps: None,
+        ema_guard_max_deviation_bps: None,
     };
 
     CONFIG.save(deps.storage, &config)?;
@@ -106,6 +134,132 @@ fn execute_governance_instruction(
                 .add_attribute("action", "set_data_sources")
                 .add_attribute("new_data_sources", format!("{data_sources:?}"))
         }
+        GovernanceAction::SetPriceValidation {
+            max_publish_time_skew,
+            max_conf_bps,
+            reject_non_positive_prices,
+        } => {
+            updated_config.max_publish_time_skew = max_publish_time_skew;
+            updated_config.max_conf_bps = max_conf_bps;
+            updated_config.reject_non_positive_prices = reject_non_positive_prices;
+
+            Response::new()
+                .add_attribute("action", "set_price_validation")
+                .add_attribute(
+                    "new_max_publish_time_skew",
+                    format!("{max_publish_time_skew:?}"),
+                )
+                .add_attribute("new_max_conf_bps", format!("{max_conf_bps:?}"))
+                .add_attribute(
+                    "new_reject_non_positive_prices",
+                    reject_non_positive_prices.to_string(),
+                )
+        }
+        GovernanceAction::Pause {} => {
+            updated_config.paused = true;
+
+            Response::new().add_attribute("action", "pause")
+        }
+        GovernanceAction::Unpause {} => {
+            updated_config.paused = false;
+
+            Response::new().add_attribute("action", "unpause")
+        }
+        GovernanceAction::SetPriceFeedsFrozen { ids, frozen } => {
+            set_price_feeds_flag(deps.storage, &FROZEN_PRICE_FEEDS, &ids, frozen)?;
+
+            Response::new()
+                .add_attribute("action", "set_price_feeds_frozen")
+                .add_attribute("ids", format!("{ids:?}"))
+                .add_attribute("frozen", frozen.to_string())
+        }
+        GovernanceAction::SetPriceFeedsDenied { ids, denied } => {
+            set_price_feeds_flag(deps.storage, &DENIED_PRICE_FEEDS, &ids, denied)?;
+
+            Response::new()
+                .add_attribute("action", "set_price_feeds_denied")
+                .add_attribute("ids", format!("{ids:?}"))
+                .add_attribute("denied", denied.to_string())
+        }
+        GovernanceAction::SetPriceFeedRedirect { from, to } => {
+            match to {
+                Some(to) if to == from => Err(ContractError::InvalidPriceFeedRedirect {})?,
+                Some(to) => PRICE_FEED_REDIRECTS.save(deps.storage, &from.to_bytes(), &to)?,
+                None => PRICE_FEED_REDIRECTS.remove(deps.storage, &from.to_bytes()),
+            }
+
+            Response::new()
+                .add_attribute("action", "set_price_feed_redirect")
+                .add_attribute("from", from.to_hex())
+                .add_attribute("to", format!("{to:?}"))
+        }
+        GovernanceAction::SetPriceFeedMetadata { id, metadata } => {
+            set_price_feed_metadata(deps.storage, id, metadata.clone())?;
+
+            Response::new()
+                .add_attribute("action", "set_price_feed_metadata")
+                .add_attribute("id", id.to_hex())
+                .add_attribute("metadata", format!("{metadata:?}"))
+        }
+        GovernanceAction::SetDenomPriceFeed { denom, price_feed } => {
+            match &price_feed {
+                Some(price_feed) => DENOM_PRICE_FEEDS.save(deps.storage, &denom, price_feed)?,
+                None => DENOM_PRICE_FEEDS.remove(deps.storage, &denom),
+            }
+
+            Response::new()
+                .add_attribute("action", "set_denom_price_feed")
+                .add_attribute("denom", denom)
+                .add_attribute("price_feed", format!("{price_feed:?}"))
+        }
+        GovernanceAction::SetDerivedPriceFeed { id, derived } => {
+            match &derived {
+                Some(DerivedPriceFeed::CrossRate { base, quote, .. }) if base == quote => {
+                    Err(ContractError::InvalidDerivedPriceFeed {})?
+                }
+                Some(DerivedPriceFeed::Basket { components, .. }) if components.is_empty() => {
+                    Err(ContractError::InvalidDerivedPriceFeed {})?
+                }
+                Some(DerivedPriceFeed::Median {
+                    components,
+                    min_components,
+                    ..
+                }) if components.len() < usize::from(*min_components).max(1) => {
+                    Err(ContractError::InvalidDerivedPriceFeed {})?
+                }
+                Some(derived) => DERIVED_PRICE_FEEDS.save(deps.storage, &id.to_bytes(), derived)?,
+                None => DERIVED_PRICE_FEEDS.remove(deps.storage, &id.to_bytes()),
+            }
+
+            Response::new()
+                .add_attribute("action", "set_derived_price_feed")
+                .add_attribute("id", id.to_hex())
+                .add_attribute("derived", format!("{derived:?}"))
+        }
+        GovernanceAction::SetConfMultiplier {
+            conf_multiplier_bps,
+        } => {
+            updated_config.conf_multiplier = Decimal256::from_ratio(conf_multiplier_bps, 10_000u64);
+
+            Response::new()
+                .add_attribute("action", "set_conf_multiplier")
+                .add_attribute(
+                    "new_conf_multiplier",
+                    updated_config.conf_multiplier.to_string(),
+                )
+        }
+        GovernanceAction::SetEmaGuard {
+            max_conf_bps,
+            max_deviation_bps,
+        } => {
+            updated_config.ema_guard_max_conf_bps = max_conf_bps;
+            updated_config.ema_guard_max_deviation_bps = max_deviation_bps;
+
+            Response::new()
+                .add_attribute("action", "set_ema_guard")
+                .add_attribute("new_max_conf_bps", format!("{max_conf_bps:?}"))
+                .add_attribute("new_max_deviation_bps", format!("{max_deviation_bps:?}"))
+        }
     };
 
     CONFIG.save(deps.storage, &updated_config)?;
@@ -113,36 +267,169 @@ fn execute_governance_instruction(
     Ok(response)
 }
 
+/// Add `ids` to the given feed set if `flag` is set, and remove them from it otherwise.
+fn set_price_feeds_flag(
+    storage: &mut dyn Storage,
+    feeds: &Map<&[u8], Empty>,
+    ids: &[PriceIdentifier],
+    flag: bool,
+) -> StdResult<()> {
+    for id in ids {
+        if flag {
+            feeds.save(storage, &id.to_bytes(), &Empty {})?;
+        } else {
+            feeds.remove(storage, &id.to_bytes());
+        }
+    }
+
+    Ok(())
+}
+
+/// Replace the registry entry for `id`, keeping the symbol index in sync.
+fn set_price_feed_metadata(
+    storage: &mut dyn Storage,
+    id: PriceIdentifier,
+    metadata: Option<PriceFeedMetadata>,
+) -> Result<(), ContractError> {
+    if let Some(metadata) = &metadata {
+        let existing = PRICE_FEED_SYMBOLS.may_load(storage, &metadata.symbol)?;
+        if existing.is_some_and(|existing| existing != id) {
+            Err(ContractError::PriceFeedSymbolAlreadyExists {})?
+        }
+    }
+
+    if let Some(previous) = PRICE_FEED_METADATA.may_load(storage, &id.to_bytes())? {
+        PRICE_FEED_SYMBOLS.remove(storage, &previous.symbol);
+    }
+
+    match metadata {
+        Some(metadata) => {
+            PRICE_FEED_SYMBOLS.save(storage, &metadata.symbol, &id)?;
+            PRICE_FEED_METADATA.save(storage, &id.to_bytes(), &metadata)?;
+        }
+        None => PRICE_FEED_METADATA.remove(storage, &id.to_bytes()),
+    }
+
+    Ok(())
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> Result<Binary, ContractError> {
     match msg {
         QueryMsg::ParsePriceFeeds {
             update_data,
             price_feed_ids,
             min_publish_time,
             max_publish_time,
-        } => to_json_binary(&query_parse_price_feed_updates(
+            normalize,
+        } => Ok(to_json_binary(&query_parse_price_feed_updates(
             deps,
             &env,
             &update_data,
             price_feed_ids,
             min_publish_time,
             max_publish_time,
-        )?),
+            normalize,
+        )?)?),
 
         QueryMsg::ParseSinglePriceFeed {
             update_data,
             price_feed_id,
             min_publish_time,
             max_publish_time,
-        } => to_json_binary(&query_parse_single_price_feed_update(
+            normalize,
+        } => Ok(to_json_binary(&query_parse_single_price_feed_update(
             deps,
             &env,
             &update_data,
             price_feed_id,
             min_publish_time,
             max_publish_time,
+            normalize,
+        )?)?),
+
+        QueryMsg::FrozenPriceFeeds { start_after, limit } => Ok(to_json_binary(
+            &query_price_feed_ids(deps, &FROZEN_PRICE_FEEDS, start_after, limit)?,
+        )?),
+
+        QueryMsg::DeniedPriceFeeds { start_after, limit } => Ok(to_json_binary(
+            &query_price_feed_ids(deps, &DENIED_PRICE_FEEDS, start_after, limit)?,
         )?),
+
+        QueryMsg::ResolvePriceFeedId { id } => Ok(to_json_binary(&ResolvePriceFeedIdResponse {
+            id,
+            resolved_id: resolve_price_feed_id(&deps, id)?,
+        })?),
+
+        QueryMsg::PriceFeedMetadata { id } => {
+            Ok(to_json_binary(&query_price_feed_metadata(deps, id)?)?)
+        }
+
+        QueryMsg::PriceFeedBySymbol { symbol } => {
+            Ok(to_json_binary(&query_price_feed_by_symbol(deps, symbol)?)?)
+        }
+
+        QueryMsg::PriceFeedRegistry { start_after, limit } => Ok(to_json_binary(
+            &query_price_feed_registry(deps, start_after, limit)?,
+        )?),
+
+        QueryMsg::DerivedPriceFeed { id } => Ok(to_json_binary(
+            &DERIVED_PRICE_FEEDS
+                .may_load(deps.storage, &id.to_bytes())?
+                .ok_or(ContractError::PriceFeedNotFound {})?,
+        )?),
+
+        QueryMsg::DenomPriceFeed { denom } => {
+            Ok(to_json_binary(&load_denom_price_feed(&deps, &denom)?)?)
+        }
+
+        QueryMsg::PriceBounds {
+            update_data,
+            price_feed_id,
+            min_publish_time,
+            max_publish_time,
+            conf_multiplier,
+        } => Ok(to_json_binary(&query_price_bounds(
+            deps,
+            &env,
+            &update_data,
+            price_feed_id,
+            min_publish_time,
+            max_publish_time,
+            conf_multiplier,
+        )?)?),
+
+        QueryMsg::GuardedPrice {
+            update_data,
+            price_feed_id,
+            min_publish_time,
+            max_publish_time,
+            mode,
+        } => Ok(to_json_binary(&query_guarded_price(
+            deps,
+            &env,
+            &update_data,
+            price_feed_id,
+            min_publish_time,
+            max_publish_time,
+            mode,
+        )?)?),
+
+        QueryMsg::ValueOf {
+            update_data,
+            coins,
+            quote,
+            min_publish_time,
+            max_publish_time,
+        } => Ok(to_json_binary(&query_value_of(
+            deps,
+            &env,
+            &update_data,
+            coins,
+            quote,
+            min_publish_time,
+            max_publish_time,
+        )?)?),
     }
 }
 
@@ -153,46 +440,74 @@ pub fn query_parse_price_feed_updates(
     price_feeds: Vec<PriceIdentifier>,
     min_publish_time: UnixTimestamp,
     max_publish_time: UnixTimestamp,
-) -> StdResult<ParsePriceFeedsResponse> {
-    let mut found_feeds = 0;
-    let mut results: Vec<(Identifier, Option<PriceFeed>)> =
-        price_feeds.iter().map(|id| (*id, None)).collect();
+    normalize: Option<PriceNormalization>,
+) -> Result<ParsePriceFeedsResponse, ContractError> {
+    ensure_not_paused(&deps)?;
 
-    for datum in updates {
-        let feeds = parse_update(&deps, env, datum)?;
+    let feeds = parse_updates(&deps, env, updates)?;
+    let mut results = vec![];
+    let mut skipped_price_feeds = vec![];
+    let mut redirects = vec![];
+    let mut aggregates = vec![];
+    for id in price_feeds {
+        let resolved_id = resolve_price_feed_id(&deps, id)?;
+        if let Some(reason) = price_feed_skip_reason(&deps, &resolved_id) {
+            skipped_price_feeds.push(SkippedPriceFeed { id, reason });
+            continue;
+        }
 
-        for result in results.as_mut_slice() {
-            if result.1.is_some() {
-                continue;
-            }
+        if resolved_id != id {
+            redirects.push(PriceFeedRedirect {
+                from: id,
+                to: resolved_id,
+            });
+        }
 
-            for feed in feeds.as_slice() {
-                if feed.get_price_unchecked().publish_time < min_publish_time
-                    || feed.get_price_unchecked().publish_time > max_publish_time
-                {
-                    continue;
-                }
+        let found = find_price_feed(
+            &deps,
+            env,
+            &feeds,
+            resolved_id,
+            min_publish_time,
+            max_publish_time,
+        )?
+        .ok_or_else(|| StdError::generic_err("Invalid update data"))?;
 
-                if result.0 == feed.id {
-                    result.1 = Some(*feed);
-                    found_feeds += 1;
-                    break;
-                }
-            }
+        if let Some(components) = found.aggregated_from {
+            aggregates.push(AggregatedPriceFeed {
+                id: resolved_id,
+                components,
+            });
         }
+        results.push(found.feed);
     }
 
-    if found_feeds != price_feeds.len() {
-        Err(StdError::generic_err("Invalid update data"))?
-    }
-
-    let unwrapped_feeds = results
-        .into_iter()
-        .map(|(_, feed)| feed.unwrap())
-        .collect::<Vec<PriceFeed>>();
+    let normalized_price_feeds = match normalize {
+        Some(normalize) => {
+            let decimals = normalize
+                .decimals
+                .unwrap_or(SignedDecimal256::DECIMAL_PLACES);
+            let normalized = results
+                .iter()
+                .map(|feed| {
+                    Ok(NormalizedPriceFeed {
+                        id: feed.id,
+                        price: normalize_price(&feed.get_price_unchecked(), decimals)?,
+                        ema_price: normalize_price(&feed.get_ema_price_unchecked(), decimals)?,
+                    })
+                })
+                .collect::<Result<Vec<NormalizedPriceFeed>, ContractError>>()?;
+            Some(normalized)
+        }
+        None => None,
+    };
 
     Ok(ParsePriceFeedsResponse {
-        price_feeds: unwrapped_feeds,
+        price_feeds: results,
+        skipped_price_feeds,
+        redirects,
+        aggregates,
+        normalized_price_feeds,
     })
 }
 
@@ -203,25 +518,1546 @@ pub fn query_parse_single_price_feed_update(
     price_feed: PriceIdentifier,
     min_publish_time: UnixTimestamp,
     max_publish_time: UnixTimestamp,
-) -> StdResult<ParseSinglePriceFeedResponse> {
-    let mut price = Price::default();
+    normalize: Option<PriceNormalization>,
+) -> Result<ParseSinglePriceFeedResponse, ContractError> {
+    ensure_not_paused(&deps)?;
+
     let feeds = parse_update(&deps, env, update_data)?;
+    let found = lookup_price_feed(
+        &deps,
+        env,
+        &feeds,
+        price_feed,
+        min_publish_time,
+        max_publish_time,
+    )?;
 
-    for feed in feeds {
-        let feed_price = feed.get_price_unchecked();
-        if feed.id == price_feed
-            && feed_price.publish_time > min_publish_time
-            && feed_price.publish_time < max_publish_time
-        {
-            price = feed_price;
-            break;
-        } else {
-            Err(StdError::generic_err("Price not found within range"))?
+    let redirect = (found.feed.id != price_feed).then_some(PriceFeedRedirect {
+        from: price_feed,
+        to: found.feed.id,
+    });
+
+    let price = found.feed.get_price_unchecked();
+    let normalized_price = match normalize {
+        Some(normalize) => Some(normalize_price(
+            &price,
+            normalize
+                .decimals
+                .unwrap_or(SignedDecimal256::DECIMAL_PLACES),
+        )?),
+        None => None,
+    };
+
+    Ok(ParseSinglePriceFeedResponse {
+        price,
+        redirect,
+        aggregated_from: found.aggregated_from,
+        normalized_price,
+    })
+}
+
+pub fn query_value_of(
+    deps: Deps,
+    env: &Env,
+    updates: &[Binary],
+    coins: Vec<Coin>,
+    quote: Option<String>,
+    min_publish_time: UnixTimestamp,
+    max_publish_time: UnixTimestamp,
+) -> Result<ValueOfResponse, ContractError> {
+    ensure_not_paused(&deps)?;
+
+    let feeds = parse_updates(&deps, env, updates)?;
+    let quote_price = match quote {
+        Some(quote) => {
+            let quote_feed = load_denom_price_feed(&deps, &quote)?;
+            let found = lookup_price_feed(
+                &deps,
+                env,
+                &feeds,
+                quote_feed.price_feed_id,
+                min_publish_time,
+                max_publish_time,
+            )?;
+            Some(found.feed.get_price_unchecked())
+        }
+        None => None,
+    };
+
+    let mut holdings = vec![];
+    for coin in coins {
+        let denom_feed = load_denom_price_feed(&deps, &coin.denom)?;
+        let found = lookup_price_feed(
+            &deps,
+            env,
+            &feeds,
+            denom_feed.price_feed_id,
+            min_publish_time,
+            max_publish_time,
+        )?;
+        holdings.push((
+            coin.amount,
+            denom_feed.decimals,
+            found.feed.get_price_unchecked(),
+        ));
+    }
+
+    value_of(&holdings, quote_price.as_ref())
+}
+
+/// The total value of `holdings`, each given as `(amount, decimals, price)`, in the quote currency
+/// of their prices, or in units of `quote` if it is set.
+fn value_of(
+    holdings: &[(Uint128, u8, Price)],
+    quote: Option<&Price>,
+) -> Result<ValueOfResponse, ContractError> {
+    let mut value = Decimal256::zero();
+    let mut conf = Decimal256::zero();
+    for (amount, decimals, price) in holdings {
+        let unit_price =
+            u64::try_from(price.price).map_err(|_| ContractError::NonPositivePrice {})?;
+        let amount = Decimal256::from_atomics(*amount, u32::from(*decimals))
+            .map_err(|_| ContractError::PriceOverflow {})?;
+
+        value = amount
+            .checked_mul(to_decimal256(unit_price, price.expo)?)
+            .and_then(|coin_value| value.checked_add(coin_value))
+            .map_err(|_| ContractError::PriceOverflow {})?;
+        conf = amount
+            .checked_mul(to_decimal256(price.conf, price.expo)?)
+            .and_then(|coin_conf| conf.checked_add(coin_conf))
+            .map_err(|_| ContractError::PriceOverflow {})?;
+    }
+
+    let Some(quote) = quote else {
+        return Ok(ValueOfResponse { value, conf });
+    };
+
+    // Convert the total into the quote denom as decimals rather than with
+    // Price::get_price_in_quote, which would round every coin price to the exponent of its feed
+    // before it is multiplied by the amount. The confidence of the quotient is
+    // `a / b * (conf(a) / a + conf(b) / b)`, as in Price::div.
+    let quote_price = u64::try_from(quote.price)
+        .ok()
+        .filter(|quote_price| *quote_price > 0)
+        .ok_or(ContractError::NonPositivePrice {})?;
+    let quote_value = to_decimal256(quote_price, quote.expo)?;
+    let quote_conf = to_decimal256(quote.conf, quote.expo)?;
+
+    let value_in_quote = value
+        .checked_div(quote_value)
+        .map_err(|_| ContractError::PriceOverflow {})?;
+    let conf_in_quote = value_in_quote
+        .checked_mul(quote_conf)
+        .map_err(|_| ContractError::PriceOverflow {})?
+        .checked_add(conf)
+        .map_err(|_| ContractError::PriceOverflow {})?
+        .checked_div(quote_value)
+        .map_err(|_| ContractError::PriceOverflow {})?;
+
+    Ok(ValueOfResponse {
+        value: value_in_quote,
+        conf: conf_in_quote,
+    })
+}
+
+pub fn query_price_bounds(
+    deps: Deps,
+    env: &Env,
+    updates: &[Binary],
+    price_feed_id: PriceIdentifier,
+    min_publish_time: UnixTimestamp,
+    max_publish_time: UnixTimestamp,
+    conf_multiplier: Option<Decimal256>,
+) -> Result<PriceBoundsResponse, ContractError> {
+    ensure_not_paused(&deps)?;
+
+    let config = CONFIG.load(deps.storage)?;
+    let feeds = parse_updates(&deps, env, updates)?;
+    let found = lookup_price_feed(
+        &deps,
+        env,
+        &feeds,
+        price_feed_id,
+        min_publish_time,
+        max_publish_time,
+    )?;
+
+    price_bounds(
+        &found.feed,
+        price_feed_id,
+        conf_multiplier,
+        config.conf_multiplier,
+    )
+}
+
+/// The bounds `conf_multiplier` confidence intervals around the price of `feed`, which was found
+/// for the requested id `price_feed_id`. `conf_multiplier` defaults to `default_conf_multiplier`.
+fn price_bounds(
+    feed: &PriceFeed,
+    price_feed_id: PriceIdentifier,
+    conf_multiplier: Option<Decimal256>,
+    default_conf_multiplier: Decimal256,
+) -> Result<PriceBoundsResponse, ContractError> {
+    let conf_multiplier = conf_multiplier.unwrap_or(default_conf_multiplier);
+    let price = feed.get_price_unchecked();
+    let unit_price = u64::try_from(price.price)
+        .ok()
+        .filter(|unit_price| *unit_price > 0)
+        .ok_or(ContractError::NonPositivePrice {})?;
+
+    let value = to_decimal256(unit_price, price.expo)?;
+    let conf = to_decimal256(price.conf, price.expo)?;
+    let margin = conf
+        .checked_mul(conf_multiplier)
+        .map_err(|_| ContractError::PriceOverflow {})?;
+
+    Ok(PriceBoundsResponse {
+        id: feed.id,
+        redirect: (feed.id != price_feed_id).then_some(PriceFeedRedirect {
+            from: price_feed_id,
+            to: feed.id,
+        }),
+        price: value,
+        conf,
+        lower: value.saturating_sub(margin),
+        upper: value
+            .checked_add(margin)
+            .map_err(|_| ContractError::PriceOverflow {})?,
+        conf_bps: Decimal256::from_ratio(u128::from(price.conf) * 10_000, unit_price),
+    })
+}
+
+pub fn query_guarded_price(
+    deps: Deps,
+    env: &Env,
+    updates: &[Binary],
+    price_feed_id: PriceIdentifier,
+    min_publish_time: UnixTimestamp,
+    max_publish_time: UnixTimestamp,
+    mode: EmaGuardMode,
+) -> Result<GuardedPriceResponse, ContractError> {
+    ensure_not_paused(&deps)?;
+
+    let config = CONFIG.load(deps.storage)?;
+    let feeds = parse_updates(&deps, env, updates)?;
+    let found = lookup_price_feed(
+        &deps,
+        env,
+        &feeds,
+        price_feed_id,
+        min_publish_time,
+        max_publish_time,
+    )?;
+
+    let redirect = (found.feed.id != price_feed_id).then_some(PriceFeedRedirect {
+        from: price_feed_id,
+        to: found.feed.id,
+    });
+
+    let (price, used_ema) = guard_price(
+        &found.feed.get_price_unchecked(),
+        &found.feed.get_ema_price_unchecked(),
+        config.ema_guard_max_conf_bps,
+        config.ema_guard_max_deviation_bps,
+        mode,
+    )?;
+
+    Ok(GuardedPriceResponse {
+        price,
+        used_ema,
+        redirect,
+        aggregated_from: found.aggregated_from,
+    })
+}
+
+/// Check the spot `price` against the EMA guard thresholds, returning the price to use and
+/// whether it is `ema_price`. A threshold that is `None` is not checked.
+fn guard_price(
+    price: &Price,
+    ema_price: &Price,
+    max_conf_bps: Option<u64>,
+    max_deviation_bps: Option<u64>,
+    mode: EmaGuardMode,
+) -> Result<(Price, bool), ContractError> {
+    let conf_too_wide = max_conf_bps.is_some_and(|max_conf_bps| {
+        u128::from(price.conf) * 10_000
+            > u128::from(price.price.unsigned_abs()) * u128::from(max_conf_bps)
+    });
+
+    let deviation_too_large = match max_deviation_bps {
+        Some(max_deviation_bps) => {
+            let ema = ema_price
+                .scale_to_exponent(price.expo)
+                .ok_or(ContractError::PriceOverflow {})?;
+            let deviation = (i128::from(price.price) - i128::from(ema.price)).unsigned_abs();
+            deviation * 10_000
+                > u128::from(ema.price.unsigned_abs()) * u128::from(max_deviation_bps)
+        }
+        None => false,
+    };
+
+    if !conf_too_wide && !deviation_too_large {
+        return Ok((*price, false));
+    }
+
+    match mode {
+        EmaGuardMode::FallbackToEma => Ok((*ema_price, true)),
+        EmaGuardMode::Fail => Err(ContractError::EmaGuardTriggered {}),
+    }
+}
+
+fn load_denom_price_feed(deps: &Deps, denom: &str) -> Result<DenomPriceFeed, ContractError> {
+    DENOM_PRICE_FEEDS
+        .may_load(deps.storage, denom)?
+        .ok_or_else(|| ContractError::DenomNotFound {
+            denom: denom.to_string(),
+        })
+}
+
+/// Parse and verify every update in `updates`, returning all the price feeds they contain.
+fn parse_updates(
+    deps: &Deps,
+    env: &Env,
+    updates: &[Binary],
+) -> Result<Vec<PriceFeed>, ContractError> {
+    let mut feeds = vec![];
+    for datum in updates {
+        feeds.extend(parse_update(deps, env, datum)?);
+    }
+
+    Ok(feeds)
+}
+
+/// Find the price feed `id` among `feeds`, following governance redirects and rejecting feeds
+/// that governance disabled.
+fn lookup_price_feed(
+    deps: &Deps,
+    env: &Env,
+    feeds: &[PriceFeed],
+    id: PriceIdentifier,
+    min_publish_time: UnixTimestamp,
+    max_publish_time: UnixTimestamp,
+) -> Result<FoundPriceFeed, ContractError> {
+    let id = resolve_price_feed_id(deps, id)?;
+    ensure_price_feed_enabled(deps, &id)?;
+
+    let found = find_price_feed(deps, env, feeds, id, min_publish_time, max_publish_time)?;
+
+    Ok(found.ok_or_else(|| StdError::generic_err("Price not found within range"))?)
+}
+
+/// A price feed found in, or evaluated from, a set of price updates.
+struct FoundPriceFeed {
+    feed: PriceFeed,
+    /// For aggregate feeds, the components the price was aggregated from.
+    aggregated_from: Option<Vec<PriceIdentifier>>,
+}
+
+impl From<PriceFeed> for FoundPriceFeed {
+    fn from(feed: PriceFeed) -> Self {
+        FoundPriceFeed {
+            feed,
+            aggregated_from: None,
+        }
+    }
+}
+
+/// Find the price feed `id` among `feeds`, evaluating it from its components if governance
+/// defined it as a derived feed. Returns `None` if the feed or one of its components is not in
+/// `feeds` within the publish time range. Fails if a price used does not pass [validate_price].
+///
+/// `id` itself must already be resolved and enabled. Components are resolved and checked here, so
+/// that redirecting or disabling a feed also applies to the derived feeds built on it.
+fn find_price_feed(
+    deps: &Deps,
+    env: &Env,
+    feeds: &[PriceFeed],
+    id: PriceIdentifier,
+    min_publish_time: UnixTimestamp,
+    max_publish_time: UnixTimestamp,
+) -> Result<Option<FoundPriceFeed>, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let find = |id| {
+        let feed = find_ordinary_price_feed(feeds, id, min_publish_time, max_publish_time);
+        match feed {
+            Some(feed) => {
+                validate_price(&config, env, &feed.get_price_unchecked()).map(|_| Some(feed))
+            }
+            None => Ok(None),
+        }
+    };
+    let find_component = |id| -> Result<Option<PriceFeed>, ContractError> {
+        let id = resolve_price_feed_id(deps, id)?;
+        ensure_price_feed_enabled(deps, &id)?;
+        find(id)
+    };
+
+    let derived = match DERIVED_PRICE_FEEDS.may_load(deps.storage, &id.to_bytes())? {
+        Some(derived) => derived,
+        None => return Ok(find(id)?.map(FoundPriceFeed::from)),
+    };
+
+    let feed = match derived {
+        DerivedPriceFeed::CrossRate { base, quote, expo } => {
+            let (Some(base), Some(quote)) = (find_component(base)?, find_component(quote)?) else {
+                return Ok(None);
+            };
+
+            PriceFeed::new(
+                id,
+                price_in_quote(
+                    &base.get_price_unchecked(),
+                    &quote.get_price_unchecked(),
+                    expo,
+                )?,
+                price_in_quote(
+                    &base.get_ema_price_unchecked(),
+                    &quote.get_ema_price_unchecked(),
+                    expo,
+                )?,
+            )
+        }
+        DerivedPriceFeed::Inverse { feed, expo } => {
+            let Some(feed) = find_component(feed)? else {
+                return Ok(None);
+            };
+
+            PriceFeed::new(
+                id,
+                inverse_price(&feed.get_price_unchecked(), expo)?,
+                inverse_price(&feed.get_ema_price_unchecked(), expo)?,
+            )
+        }
+        DerivedPriceFeed::Basket { components, expo } => {
+            let mut prices = vec![];
+            let mut ema_prices = vec![];
+            for component in components {
+                let Some(feed) = find_component(component.id)? else {
+                    return Ok(None);
+                };
+
+                let (quantity, quantity_expo) = (component.quantity, component.quantity_expo);
+                prices.push((feed.get_price_unchecked(), quantity, quantity_expo));
+                ema_prices.push((feed.get_ema_price_unchecked(), quantity, quantity_expo));
+            }
+
+            PriceFeed::new(
+                id,
+                basket_price(&prices, expo)?,
+                basket_price(&ema_prices, expo)?,
+            )
         }
+        DerivedPriceFeed::Median {
+            components,
+            max_age,
+            max_deviation_bps,
+            min_components,
+        } => {
+            // Only enabled, valid components published within `max_age` of the block time take
+            // part. Unlike the other derived feeds, a median drops the rest instead of failing.
+            let oldest_allowed = i128::from(env.block.time.seconds()) - i128::from(max_age);
+            let mut fresh = vec![];
+            for id in components {
+                let id = resolve_price_feed_id(deps, id)?;
+                if price_feed_skip_reason(deps, &id).is_some() {
+                    continue;
+                }
+
+                let Some(feed) =
+                    find_ordinary_price_feed(feeds, id, min_publish_time, max_publish_time)
+                else {
+                    continue;
+                };
+
+                let price = feed.get_price_unchecked();
+                if i128::from(price.publish_time) >= oldest_allowed
+                    && validate_price(&config, env, &price).is_ok()
+                {
+                    fresh.push(feed);
+                }
+            }
+
+            let (price, ema_price, aggregated_from) =
+                median_aggregate(&fresh, max_deviation_bps, min_components)?;
+
+            return Ok(Some(FoundPriceFeed {
+                feed: PriceFeed::new(id, price, ema_price),
+                aggregated_from: Some(aggregated_from),
+            }));
+        }
+    };
+
+    Ok(Some(feed.into()))
+}
+
+/// Find the price feed `id` as published in `feeds`, without resolving derived feeds.
+fn find_ordinary_price_feed(
+    feeds: &[PriceFeed],
+    id: PriceIdentifier,
+    min_publish_time: UnixTimestamp,
+    max_publish_time: UnixTimestamp,
+) -> Option<PriceFeed> {
+    feeds.iter().copied().find(|feed| {
+        let publish_time = feed.get_price_unchecked().publish_time;
+        feed.id == id && publish_time >= min_publish_time && publish_time <= max_publish_time
+    })
+}
+
+/// The price of `base` in units of `quote`, expressed with the exponent `expo` and published at
+/// the older of the two publish times.
+fn price_in_quote(base: &Price, quote: &Price, expo: i32) -> Result<Price, ContractError> {
+    if base.price <= 0 || quote.price <= 0 {
+        return Err(ContractError::NonPositivePrice {});
+    }
+
+    let price = widen_mantissa(base)
+        .get_price_in_quote(quote, expo)
+        .ok_or(ContractError::PriceOverflow {})?;
+
+    Ok(Price {
+        publish_time: base.publish_time.min(quote.publish_time),
+        ..price
+    })
+}
+
+/// `price` rescaled so that its mantissa has as many digits as Price::div keeps. The quotient of
+/// Price::div has about as many significant digits as the mantissa of the dividend, so a short
+/// mantissa would otherwise leave only a few digits in the result.
+fn widen_mantissa(price: &Price) -> Price {
+    let mut mantissa = price.price.unsigned_abs();
+    let mut expo = price.expo;
+    while mantissa != 0 && mantissa <= MAX_DIVISION_MANTISSA / 10 {
+        mantissa *= 10;
+        expo -= 1;
+    }
+
+    price.scale_to_exponent(expo).unwrap_or(*price)
+}
+
+/// The weighted sum of `amounts`, each given as `(price, quantity, quantity_expo)`, published at
+/// the oldest of the component publish times.
+fn basket_price(amounts: &[(Price, i64, i32)], expo: i32) -> Result<Price, ContractError> {
+    let price = Price::price_basket(amounts, expo).ok_or(ContractError::PriceOverflow {})?;
+    let publish_time = amounts
+        .iter()
+        .map(|(price, _, _)| price.publish_time)
+        .min()
+        .unwrap_or(price.publish_time);
+
+    Ok(Price {
+        publish_time,
+        ..price
+    })
+}
+
+/// The median spot and EMA prices of `components`, and the ids of the components they were
+/// aggregated from. Components are dropped if their price is not positive, or if they deviate from
+/// the median of all of them by more than `max_deviation_bps` basis points. The median is given at
+/// the exponent most of the remaining components share, and at least `min_components` of them
+/// must remain.
+fn median_aggregate(
+    components: &[PriceFeed],
+    max_deviation_bps: u64,
+    min_components: u8,
+) -> Result<(Price, Price, Vec<PriceIdentifier>), ContractError> {
+    // A component without a positive price has no usable price, and a median of zero would make
+    // every other component an outlier. The rest are compared as decimals, since bringing them to
+    // a common exponent can overflow when one of them is published at a very fine exponent.
+    let mut valued = components
+        .iter()
+        .filter_map(|feed| {
+            let price = feed.get_price_unchecked();
+            let unit_price = u64::try_from(price.price).ok()?;
+            let value = to_decimal256(unit_price, price.expo)
+                .ok()
+                .filter(|value| !value.is_zero())?;
+            Some((feed, value))
+        })
+        .collect::<Vec<_>>();
+
+    // Drop outliers relative to the median of all remaining components.
+    let median = median_decimal(valued.iter().map(|(_, value)| *value).collect())?;
+    valued.retain(|(_, value)| {
+        let deviation = Uint512::from(value.abs_diff(median).atomics());
+        deviation * Uint512::from(10_000u64)
+            <= Uint512::from(median.atomics()) * Uint512::from(max_deviation_bps)
+    });
+
+    // Of the exponents the most components share, pick the finest.
+    let expos = valued
+        .iter()
+        .map(|(feed, _)| feed.get_price_unchecked().expo)
+        .collect::<Vec<_>>();
+    let expo = expos
+        .iter()
+        .copied()
+        .max_by_key(|expo| {
+            let shared = expos.iter().filter(|other| *other == expo).count();
+            (shared, Reverse(*expo))
+        })
+        .ok_or(ContractError::InsufficientAggregateComponents {})?;
+    let scaled = valued
+        .into_iter()
+        .filter_map(|(feed, _)| {
+            let price = feed.get_price_unchecked().scale_to_exponent(expo)?;
+            let ema_price = feed.get_ema_price_unchecked().scale_to_exponent(expo)?;
+            Some((feed.id, price, ema_price))
+        })
+        .collect::<Vec<_>>();
+
+    if scaled.len() < usize::from(min_components).max(1) {
+        Err(ContractError::InsufficientAggregateComponents {})?
+    }
+
+    let prices = scaled
+        .iter()
+        .map(|(_, price, _)| *price)
+        .collect::<Vec<_>>();
+    let ema_prices = scaled
+        .iter()
+        .map(|(_, _, ema_price)| *ema_price)
+        .collect::<Vec<_>>();
+
+    Ok((
+        median_price(&prices)?,
+        median_price(&ema_prices)?,
+        scaled.into_iter().map(|(id, _, _)| id).collect(),
+    ))
+}
+
+/// The median of `values`. With an even number of values, the mean of the middle two.
+fn median_decimal(mut values: Vec<Decimal256>) -> Result<Decimal256, ContractError> {
+    values.sort();
+
+    let mid = values.len() / 2;
+    match values.len() {
+        0 => Err(ContractError::InsufficientAggregateComponents {}),
+        len if len % 2 == 1 => Ok(values[mid]),
+        _ => {
+            let (lower, upper) = (values[mid - 1], values[mid]);
+            Ok(Decimal256::new(
+                lower.atomics() + (upper - lower).atomics() / Uint256::from(2u8),
+            ))
+        }
+    }
+}
+
+/// The median of `prices`, which must share an exponent. With an even number of prices, the price
+/// and confidence are the means of the middle two. Published at the oldest publish time.
+fn median_price(prices: &[Price]) -> Result<Price, ContractError> {
+    let mut sorted = prices.to_vec();
+    sorted.sort_by_key(|price| price.price);
+
+    let mid = sorted.len() / 2;
+    let (price, conf) = match sorted.len() {
+        0 => Err(ContractError::InsufficientAggregateComponents {})?,
+        len if len % 2 == 1 => (sorted[mid].price, sorted[mid].conf),
+        _ => {
+            let (lower, upper) = (sorted[mid - 1], sorted[mid]);
+            // The mean of two values always fits in the type of the values.
+            (
+                ((i128::from(lower.price) + i128::from(upper.price)) / 2) as i64,
+                ((u128::from(lower.conf) + u128::from(upper.conf)) / 2) as u64,
+            )
+        }
+    };
+
+    Ok(Price {
+        price,
+        conf,
+        expo: sorted[0].expo,
+        publish_time: sorted
+            .iter()
+            .map(|price| price.publish_time)
+            .min()
+            .unwrap_or_default(),
+    })
+}
+
+/// The reciprocal of `price`, expressed with the exponent `expo`.
+fn inverse_price(price: &Price, expo: i32) -> Result<Price, ContractError> {
+    // One is written with a full mantissa, see [widen_mantissa].
+    let one = Price {
+        price: 100_000_000,
+        conf: 0,
+        expo: -8,
+        publish_time: price.publish_time,
+    };
+
+    price_in_quote(&one, price, expo)
+}
+
+/// Fail with [ContractError::Paused] if governance has paused price updates.
+fn ensure_not_paused(deps: &Deps) -> Result<(), ContractError> {
+    if CONFIG.load(deps.storage)?.paused {
+        return Err(ContractError::Paused {});
+    }
+
+    Ok(())
+}
+
+/// The id that governance redirected the price feed `id` to, or `id` itself if it has no
+/// redirect. Redirects are followed for a single hop only.
+fn resolve_price_feed_id(deps: &Deps, id: PriceIdentifier) -> StdResult<PriceIdentifier> {
+    Ok(PRICE_FEED_REDIRECTS
+        .may_load(deps.storage, &id.to_bytes())?
+        .unwrap_or(id))
+}
+
+/// Fail if governance froze or deny-listed the price feed `id`.
+fn ensure_price_feed_enabled(deps: &Deps, id: &PriceIdentifier) -> Result<(), ContractError> {
+    match price_feed_skip_reason(deps, id) {
+        Some(SkipReason::Frozen) => Err(ContractError::PriceFeedFrozen {}),
+        Some(SkipReason::Denied) => Err(ContractError::PriceFeedDenied {}),
+        None => Ok(()),
+    }
+}
+
+/// Why governance disabled the price feed `id`, if it did. Deny-listing takes precedence over
+/// freezing.
+fn price_feed_skip_reason(deps: &Deps, id: &PriceIdentifier) -> Option<SkipReason> {
+    if DENIED_PRICE_FEEDS.has(deps.storage, &id.to_bytes()) {
+        return Some(SkipReason::Denied);
+    }
+
+    if FROZEN_PRICE_FEEDS.has(deps.storage, &id.to_bytes()) {
+        return Some(SkipReason::Frozen);
     }
 
-    Ok(ParseSinglePriceFeedResponse { price })
+    None
+}
+
+pub fn query_price_feed_ids(
+    deps: Deps,
+    feeds: &Map<&[u8], Empty>,
+    start_after: Option<PriceIdentifier>,
+    limit: Option<u32>,
+) -> StdResult<PriceFeedIdsResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(|id| id.to_bytes());
+    let min = start.as_ref().map(|id| Bound::exclusive(id.as_slice()));
+
+    let ids = feeds
+        .keys(deps.storage, min, None, Order::Ascending)
+        .take(limit)
+        .map(|key| key.and_then(|key| price_feed_id_from_key(&key)))
+        .collect::<StdResult<Vec<PriceIdentifier>>>()?;
+
+    Ok(PriceFeedIdsResponse { ids })
+}
+
+/// Convert a storage key back into the price feed id it was built from.
+fn price_feed_id_from_key(key: &[u8]) -> StdResult<PriceIdentifier> {
+    let bytes: [u8; 32] = key
+        .try_into()
+        .map_err(|_| StdError::generic_err("Invalid price feed id key"))?;
+
+    Ok(PriceIdentifier::new(bytes))
+}
+
+pub fn query_price_feed_metadata(
+    deps: Deps,
+    id: PriceIdentifier,
+) -> Result<RegisteredPriceFeed, ContractError> {
+    let metadata = PRICE_FEED_METADATA
+        .may_load(deps.storage, &id.to_bytes())?
+        .ok_or(ContractError::PriceFeedNotFound {})?;
+
+    Ok(RegisteredPriceFeed { id, metadata })
+}
+
+pub fn query_price_feed_by_symbol(
+    deps: Deps,
+    symbol: String,
+) -> Result<RegisteredPriceFeed, ContractError> {
+    let id = PRICE_FEED_SYMBOLS
+        .may_load(deps.storage, &symbol)?
+        .ok_or(ContractError::PriceFeedNotFound {})?;
+
+    query_price_feed_metadata(deps, id)
+}
+
+pub fn query_price_feed_registry(
+    deps: Deps,
+    start_after: Option<PriceIdentifier>,
+    limit: Option<u32>,
+) -> StdResult<PriceFeedRegistryResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(|id| id.to_bytes());
+    let min = start.as_ref().map(|id| Bound::exclusive(id.as_slice()));
+
+    let price_feeds = PRICE_FEED_METADATA
+        .range(deps.storage, min, None, Order::Ascending)
+        .take(limit)
+        .map(|item| {
+            let (key, metadata) = item?;
+            Ok(RegisteredPriceFeed {
+                id: price_feed_id_from_key(&key)?,
+                metadata,
+            })
+        })
+        .collect::<StdResult<Vec<RegisteredPriceFeed>>>()?;
+
+    Ok(PriceFeedRegistryResponse { price_feeds })
 }
 
 #[cfg(test)]
-mod tests {}
+mod tests {
+    use std::str::FromStr;
+
+    use cosmwasm_std::{
+        from_json,
+        testing::{mock_dependencies, mock_env},
+    };
+
+    use super::*;
+
+    fn price(price: i64, conf: u64, expo: i32) -> Price {
+        Price {
+            price,
+            conf,
+            expo,
+            publish_time: 1_700_000_000,
+        }
+    }
+
+    #[test]
+    fn test_inverse_price() {
+        // ATOM/USD 7.23 +- 0.003615 gives USD/ATOM 0.13831258 +- 0.00006915.
+        let atom = price(723_000_000, 361_500, -8);
+        assert_eq!(
+            inverse_price(&atom, -8).unwrap(),
+            price(13_831_258, 6_915, -8)
+        );
+        assert_eq!(
+            inverse_price(&atom, -12).unwrap(),
+            price(138_312_586_400, 69_156_200, -12)
+        );
+
+        // USDC/USD 0.99987 gives USD/USDC 1.00013001, not 1.
+        let usdc = price(99_987_000, 5_000, -8);
+        assert_eq!(
+            inverse_price(&usdc, -8).unwrap(),
+            price(100_013_001, 5_001, -8)
+        );
+    }
+
+    #[test]
+    fn test_inverse_price_non_positive() {
+        assert!(matches!(
+            inverse_price(&price(0, 0, -8), -8),
+            Err(ContractError::NonPositivePrice {})
+        ));
+        assert!(matches!(
+            inverse_price(&price(-723_000_000, 0, -8), -8),
+            Err(ContractError::NonPositivePrice {})
+        ));
+    }
+
+    #[test]
+    fn test_price_in_quote() {
+        let atom = price(723_000_000, 361_500, -8);
+        let osmo = price(41_230_000, 20_000, -8);
+        let btc = price(6_712_345_000_000, 3_500_000_000, -8);
+
+        // ATOM/OSMO = 7.23 / 0.4123 = 17.53577492
+        assert_eq!(
+            price_in_quote(&atom, &osmo, -8).unwrap(),
+            price(1_753_577_492, 1_727_419, -8)
+        );
+
+        // OSMO/BTC = 0.4123 / 67123.45 = 0.000006142413, which has only three digits at the
+        // exponent of the inputs.
+        assert_eq!(
+            price_in_quote(&osmo, &btc, -12).unwrap(),
+            price(6_142_413, 6_182, -12)
+        );
+
+        // A short mantissa in the base does not cost precision.
+        assert_eq!(
+            price_in_quote(&price(723, 1, -2), &osmo, -8).unwrap(),
+            price(1_753_577_492, 3_276_047, -8)
+        );
+    }
+
+    #[test]
+    fn test_price_in_quote_publish_time() {
+        let base = Price {
+            publish_time: 1_700_000_010,
+            ..price(723_000_000, 0, -8)
+        };
+        let quote = price(41_230_000, 0, -8);
+
+        assert_eq!(
+            price_in_quote(&base, &quote, -8).unwrap().publish_time,
+            quote.publish_time
+        );
+        assert_eq!(
+            price_in_quote(&quote, &base, -8).unwrap().publish_time,
+            quote.publish_time
+        );
+    }
+
+    #[test]
+    fn test_price_in_quote_non_positive() {
+        let atom = price(723_000_000, 0, -8);
+
+        assert!(matches!(
+            price_in_quote(&atom, &price(0, 0, -8), -8),
+            Err(ContractError::NonPositivePrice {})
+        ));
+        assert!(matches!(
+            price_in_quote(&price(0, 0, -8), &atom, -8),
+            Err(ContractError::NonPositivePrice {})
+        ));
+    }
+
+    fn feed(id: u8, price: Price) -> PriceFeed {
+        PriceFeed::new(PriceIdentifier::new([id; 32]), price, price)
+    }
+
+    fn ids(ids: &[u8]) -> Vec<PriceIdentifier> {
+        ids.iter()
+            .map(|id| PriceIdentifier::new([*id; 32]))
+            .collect()
+    }
+
+    #[test]
+    fn test_median_price_odd() {
+        let prices = [price(300, 3, -2), price(100, 1, -2), price(200, 2, -2)];
+
+        assert_eq!(median_price(&prices).unwrap(), price(200, 2, -2));
+    }
+
+    #[test]
+    fn test_median_price_even() {
+        let prices = [
+            price(400, 4, -2),
+            price(100, 1, -2),
+            Price {
+                publish_time: 1_699_999_990,
+                ..price(300, 3, -2)
+            },
+            price(200, 2, -2),
+        ];
+
+        // The price and confidence are the means of the middle two, published at the oldest time.
+        assert_eq!(
+            median_price(&prices).unwrap(),
+            Price {
+                publish_time: 1_699_999_990,
+                ..price(250, 2, -2)
+            }
+        );
+    }
+
+    #[test]
+    fn test_median_price_empty() {
+        assert!(matches!(
+            median_price(&[]),
+            Err(ContractError::InsufficientAggregateComponents {})
+        ));
+    }
+
+    #[test]
+    fn test_median_aggregate_drops_outliers() {
+        let components = [
+            feed(1, price(100, 1, -2)),
+            feed(2, price(101, 1, -2)),
+            feed(3, price(99, 1, -2)),
+            feed(4, price(150, 1, -2)),
+        ];
+
+        // The median of all four is 100, so 150 deviates by 50% and is dropped at 5%.
+        let (median, ema_median, aggregated_from) = median_aggregate(&components, 500, 3).unwrap();
+        assert_eq!(median, price(100, 1, -2));
+        assert_eq!(ema_median, price(100, 1, -2));
+        assert_eq!(aggregated_from, ids(&[1, 2, 3]));
+    }
+
+    #[test]
+    fn test_median_aggregate_min_components() {
+        let components = [
+            feed(1, price(100, 1, -2)),
+            feed(2, price(101, 1, -2)),
+            feed(3, price(99, 1, -2)),
+            feed(4, price(150, 1, -2)),
+        ];
+
+        assert!(matches!(
+            median_aggregate(&components, 500, 4),
+            Err(ContractError::InsufficientAggregateComponents {})
+        ));
+        assert!(median_aggregate(&components, 5_000, 4).is_ok());
+    }
+
+    #[test]
+    fn test_median_aggregate_drops_zero_prices() {
+        let components = [
+            feed(1, price(0, 0, -2)),
+            feed(2, price(100, 1, -2)),
+            feed(3, price(101, 1, -2)),
+        ];
+
+        let (median, _, aggregated_from) = median_aggregate(&components, 500, 2).unwrap();
+        assert_eq!(median, price(100, 1, -2));
+        assert_eq!(aggregated_from, ids(&[2, 3]));
+
+        assert!(matches!(
+            median_aggregate(&[feed(1, price(0, 0, -2))], 500, 1),
+            Err(ContractError::InsufficientAggregateComponents {})
+        ));
+    }
+
+    #[test]
+    fn test_median_aggregate_mixed_exponents() {
+        let components = [
+            feed(1, price(100, 1, -2)),
+            feed(2, price(101, 1, -2)),
+            feed(3, price(1_000_000, 10_000, -6)),
+            feed(4, price(i64::MAX / 10, 0, 0)),
+        ];
+
+        // Component 4 is an outlier, and the others are reported at the exponent -2 most of them
+        // share.
+        let (median, _, aggregated_from) = median_aggregate(&components, 500, 3).unwrap();
+        assert_eq!(median, price(100, 1, -2));
+        assert_eq!(aggregated_from, ids(&[1, 2, 3]));
+    }
+
+    #[test]
+    fn test_median_aggregate_fine_exponent() {
+        // Components 1 to 3 overflow at the exponent -18 of component 4, which must not decide the
+        // median on its own.
+        let components = [
+            feed(1, price(2_000_000_000, 1_000_000, -8)),
+            feed(2, price(2_010_000_000, 1_000_000, -8)),
+            feed(3, price(1_990_000_000, 1_000_000, -8)),
+            feed(4, price(5_000_000_000_000_000_000, 0, -18)),
+        ];
+
+        let (median, _, aggregated_from) = median_aggregate(&components, 500, 3).unwrap();
+        assert_eq!(median, price(2_000_000_000, 1_000_000, -8));
+        assert_eq!(aggregated_from, ids(&[1, 2, 3]));
+
+        // Without outlier rejection, the median is still taken at the shared exponent.
+        let (median, _, aggregated_from) = median_aggregate(&components, 10_000, 4).unwrap();
+        assert_eq!(median, price(1_995_000_000, 1_000_000, -8));
+        assert_eq!(aggregated_from, ids(&[1, 2, 3, 4]));
+    }
+
+    #[test]
+    fn test_median_decimal() {
+        assert!(matches!(
+            median_decimal(vec![]),
+            Err(ContractError::InsufficientAggregateComponents {})
+        ));
+        assert_eq!(
+            median_decimal(vec![decimal("3"), decimal("1"), decimal("2")]).unwrap(),
+            decimal("2")
+        );
+        assert_eq!(
+            median_decimal(vec![
+                decimal("4"),
+                decimal("1"),
+                decimal("2.5"),
+                decimal("3")
+            ])
+            .unwrap(),
+            decimal("2.75")
+        );
+    }
+
+    fn decimal(value: &str) -> Decimal256 {
+        Decimal256::from_str(value).unwrap()
+    }
+
+    #[test]
+    fn test_value_of() {
+        // 1.5 ATOM at 7.23 +- 0.003615 USD.
+        let atom = (Uint128::new(1_500_000), 6, price(723_000_000, 361_500, -8));
+        assert_eq!(
+            value_of(&[atom], None).unwrap(),
+            ValueOfResponse {
+                value: decimal("10.845"),
+                conf: decimal("0.0054225"),
+            }
+        );
+
+        // Valued in OSMO at 0.4123 +- 0.0002 USD.
+        let osmo = price(41_230_000, 20_000, -8);
+        assert_eq!(
+            value_of(&[atom], Some(&osmo)).unwrap(),
+            ValueOfResponse {
+                value: decimal("26.303662381760853747"),
+                conf: decimal("0.025911308455862648"),
+            }
+        );
+    }
+
+    #[test]
+    fn test_value_of_mixed_decimals() {
+        // 1.5 ATOM (6 decimals) and 0.25 ETH (18 decimals) at 3456.78 +- 1.5 USD.
+        let atom = (Uint128::new(1_500_000), 6, price(723_000_000, 361_500, -8));
+        let eth = (
+            Uint128::new(250_000_000_000_000_000),
+            18,
+            price(345_678_000_000, 150_000_000, -8),
+        );
+        assert_eq!(
+            value_of(&[atom, eth], None).unwrap(),
+            ValueOfResponse {
+                value: decimal("875.04"),
+                conf: decimal("0.3804225"),
+            }
+        );
+    }
+
+    #[test]
+    fn test_value_of_non_positive() {
+        let atom = (Uint128::new(1_500_000), 6, price(723_000_000, 361_500, -8));
+        for quote in [price(0, 0, -8), price(-41_230_000, 20_000, -8)] {
+            assert!(matches!(
+                value_of(&[atom], Some(&quote)),
+                Err(ContractError::NonPositivePrice {})
+            ));
+        }
+
+        let negative = (Uint128::new(1_500_000), 6, price(-723_000_000, 361_500, -8));
+        assert!(matches!(
+            value_of(&[negative], None),
+            Err(ContractError::NonPositivePrice {})
+        ));
+    }
+
+    #[test]
+    fn test_load_denom_price_feed() {
+        let mut deps = mock_dependencies();
+        let atom = DenomPriceFeed {
+            price_feed_id: PriceIdentifier::new([1; 32]),
+            decimals: 6,
+        };
+        DENOM_PRICE_FEEDS
+            .save(deps.as_mut().storage, "uatom", &atom)
+            .unwrap();
+
+        assert_eq!(
+            load_denom_price_feed(&deps.as_ref(), "uatom").unwrap(),
+            atom
+        );
+        assert!(matches!(
+            load_denom_price_feed(&deps.as_ref(), "uosmo"),
+            Err(ContractError::DenomNotFound { denom }) if denom == "uosmo"
+        ));
+    }
+
+    #[test]
+    fn test_guard_price_disabled() {
+        let spot = price(100_000_000, 50_000_000, -8);
+        let ema = price(200_000_000, 1_000_000, -8);
+        assert_eq!(
+            guard_price(&spot, &ema, None, None, EmaGuardMode::Fail).unwrap(),
+            (spot, false)
+        );
+    }
+
+    #[test]
+    fn test_guard_price_conf() {
+        let ema = price(100_000_000, 500_000, -8);
+
+        // A confidence of exactly 1% of the price is within 100 bps.
+        let spot = price(100_000_000, 1_000_000, -8);
+        assert_eq!(
+            guard_price(&spot, &ema, Some(100), None, EmaGuardMode::Fail).unwrap(),
+            (spot, false)
+        );
+
+        let spot = price(100_000_000, 1_000_001, -8);
+        assert_eq!(
+            guard_price(&spot, &ema, Some(100), None, EmaGuardMode::FallbackToEma).unwrap(),
+            (ema, true)
+        );
+        assert!(matches!(
+            guard_price(&spot, &ema, Some(100), None, EmaGuardMode::Fail),
+            Err(ContractError::EmaGuardTriggered {})
+        ));
+    }
+
+    #[test]
+    fn test_guard_price_deviation() {
+        let ema = price(100_000_000, 500_000, -8);
+
+        // A deviation of exactly 2% from the EMA is within 200 bps, in either direction.
+        for spot in [
+            price(102_000_000, 500_000, -8),
+            price(98_000_000, 500_000, -8),
+        ] {
+            assert_eq!(
+                guard_price(&spot, &ema, None, Some(200), EmaGuardMode::Fail).unwrap(),
+                (spot, false)
+            );
+        }
+
+        for spot in [
+            price(102_000_001, 500_000, -8),
+            price(97_999_999, 500_000, -8),
+        ] {
+            assert_eq!(
+                guard_price(&spot, &ema, None, Some(200), EmaGuardMode::FallbackToEma).unwrap(),
+                (ema, true)
+            );
+            assert!(matches!(
+                guard_price(&spot, &ema, None, Some(200), EmaGuardMode::Fail),
+                Err(ContractError::EmaGuardTriggered {})
+            ));
+        }
+    }
+
+    #[test]
+    fn test_guard_price_deviation_ema_expo() {
+        // The EMA is compared at the exponent of the spot price, and returned at its own.
+        let ema = price(1_000_000, 5_000, -6);
+
+        let spot = price(102_000_000, 500_000, -8);
+        assert_eq!(
+            guard_price(&spot, &ema, None, Some(200), EmaGuardMode::Fail).unwrap(),
+            (spot, false)
+        );
+
+        let spot = price(102_000_001, 500_000, -8);
+        assert_eq!(
+            guard_price(&spot, &ema, None, Some(200), EmaGuardMode::FallbackToEma).unwrap(),
+            (ema, true)
+        );
+    }
+
+    #[test]
+    fn test_price_bounds() {
+        // ATOM/USD 7.23 +- 0.003615, with the default multiplier of 2 and an override of 3.
+        let atom = feed(1, price(723_000_000, 361_500, -8));
+        let id = PriceIdentifier::new([1; 32]);
+        assert_eq!(
+            price_bounds(&atom, id, None, decimal("2")).unwrap(),
+            PriceBoundsResponse {
+                id,
+                redirect: None,
+                price: decimal("7.23"),
+                conf: decimal("0.003615"),
+                lower: decimal("7.22277"),
+                upper: decimal("7.23723"),
+                conf_bps: decimal("5"),
+            }
+        );
+        let bounds = price_bounds(&atom, id, Some(decimal("3")), decimal("2")).unwrap();
+        assert_eq!(bounds.lower, decimal("7.219155"));
+        assert_eq!(bounds.upper, decimal("7.240845"));
+    }
+
+    #[test]
+    fn test_price_bounds_lower_floored_at_zero() {
+        let wide = feed(1, price(1_000_000, 600_000, -8));
+        let bounds = price_bounds(&wide, wide.id, None, decimal("2")).unwrap();
+        assert_eq!(bounds.lower, Decimal256::zero());
+        assert_eq!(bounds.upper, decimal("0.022"));
+        assert_eq!(bounds.conf_bps, decimal("6000"));
+    }
+
+    #[test]
+    fn test_price_bounds_conf_bps() {
+        let tiny = feed(1, price(3, 1, -8));
+        let bounds = price_bounds(&tiny, tiny.id, None, decimal("1")).unwrap();
+        assert_eq!(bounds.conf_bps, decimal("3333.333333333333333333"));
+    }
+
+    #[test]
+    fn test_price_bounds_redirect() {
+        let replacement = feed(2, price(723_000_000, 361_500, -8));
+        let deprecated = PriceIdentifier::new([1; 32]);
+        let bounds = price_bounds(&replacement, deprecated, None, decimal("2")).unwrap();
+        assert_eq!(bounds.id, replacement.id);
+        assert_eq!(
+            bounds.redirect,
+            Some(PriceFeedRedirect {
+                from: deprecated,
+                to: replacement.id,
+            })
+        );
+    }
+
+    #[test]
+    fn test_price_bounds_non_positive() {
+        for value in [0, -723_000_000] {
+            let atom = feed(1, price(value, 361_500, -8));
+            assert!(matches!(
+                price_bounds(&atom, atom.id, None, decimal("2")),
+                Err(ContractError::NonPositivePrice {})
+            ));
+        }
+    }
+
+    fn metadata(symbol: &str) -> PriceFeedMetadata {
+        let (base, quote) = symbol.split_once('/').unwrap();
+        PriceFeedMetadata {
+            symbol: symbol.to_string(),
+            asset_class: "Crypto".to_string(),
+            base: base.to_string(),
+            quote: quote.to_string(),
+            expo: -8,
+        }
+    }
+
+    #[test]
+    fn test_set_price_feed_metadata() {
+        let mut deps = mock_dependencies();
+        let id = PriceIdentifier::new([1; 32]);
+
+        set_price_feed_metadata(deps.as_mut().storage, id, Some(metadata("ATOM/USD"))).unwrap();
+        assert_eq!(
+            query_price_feed_by_symbol(deps.as_ref(), "ATOM/USD".to_string()).unwrap(),
+            RegisteredPriceFeed {
+                id,
+                metadata: metadata("ATOM/USD"),
+            }
+        );
+
+        // Re-registering the feed under a new symbol releases the old one.
+        set_price_feed_metadata(deps.as_mut().storage, id, Some(metadata("ATOM/USDC"))).unwrap();
+        assert!(matches!(
+            query_price_feed_by_symbol(deps.as_ref(), "ATOM/USD".to_string()),
+            Err(ContractError::PriceFeedNotFound {})
+        ));
+        assert_eq!(
+            query_price_feed_by_symbol(deps.as_ref(), "ATOM/USDC".to_string()).unwrap(),
+            RegisteredPriceFeed {
+                id,
+                metadata: metadata("ATOM/USDC"),
+            }
+        );
+
+        set_price_feed_metadata(deps.as_mut().storage, id, None).unwrap();
+        assert!(matches!(
+            query_price_feed_by_symbol(deps.as_ref(), "ATOM/USDC".to_string()),
+            Err(ContractError::PriceFeedNotFound {})
+        ));
+        assert!(matches!(
+            query_price_feed_metadata(deps.as_ref(), id),
+            Err(ContractError::PriceFeedNotFound {})
+        ));
+    }
+
+    #[test]
+    fn test_set_price_feed_metadata_duplicate_symbol() {
+        let mut deps = mock_dependencies();
+        let atom = PriceIdentifier::new([1; 32]);
+        let other = PriceIdentifier::new([2; 32]);
+
+        set_price_feed_metadata(deps.as_mut().storage, atom, Some(metadata("ATOM/USD"))).unwrap();
+        assert!(matches!(
+            set_price_feed_metadata(deps.as_mut().storage, other, Some(metadata("ATOM/USD"))),
+            Err(ContractError::PriceFeedSymbolAlreadyExists {})
+        ));
+        assert_eq!(
+            query_price_feed_by_symbol(deps.as_ref(), "ATOM/USD".to_string())
+                .unwrap()
+                .id,
+            atom
+        );
+        assert!(matches!(
+            query_price_feed_metadata(deps.as_ref(), other),
+            Err(ContractError::PriceFeedNotFound {})
+        ));
+
+        // A feed can be registered again under its own symbol.
+        let mut updated = metadata("ATOM/USD");
+        updated.expo = -6;
+        set_price_feed_metadata(deps.as_mut().storage, atom, Some(updated.clone())).unwrap();
+        assert_eq!(
+            query_price_feed_metadata(deps.as_ref(), atom)
+                .unwrap()
+                .metadata,
+            updated
+        );
+    }
+
+    #[test]
+    fn test_query_price_feed_registry_pagination() {
+        let mut deps = mock_dependencies();
+        for (id, symbol) in [
+            (1, "ATOM/USD"),
+            (2, "OSMO/USD"),
+            (3, "ETH/USD"),
+            (4, "BTC/USD"),
+        ] {
+            set_price_feed_metadata(
+                deps.as_mut().storage,
+                PriceIdentifier::new([id; 32]),
+                Some(metadata(symbol)),
+            )
+            .unwrap();
+        }
+
+        let page = |start_after: Option<u8>, limit: Option<u32>| {
+            query_price_feed_registry(
+                deps.as_ref(),
+                start_after.map(|id| PriceIdentifier::new([id; 32])),
+                limit,
+            )
+            .unwrap()
+            .price_feeds
+            .into_iter()
+            .map(|price_feed| price_feed.id)
+            .collect::<Vec<PriceIdentifier>>()
+        };
+
+        assert_eq!(page(None, None), ids(&[1, 2, 3, 4]));
+        assert_eq!(page(None, Some(2)), ids(&[1, 2]));
+        assert_eq!(page(Some(2), Some(2)), ids(&[3, 4]));
+        assert_eq!(page(Some(4), None), ids(&[]));
+    }
+
+    #[test]
+    fn test_set_price_feeds_flag() {
+        let mut deps = mock_dependencies();
+
+        set_price_feeds_flag(
+            deps.as_mut().storage,
+            &FROZEN_PRICE_FEEDS,
+            &ids(&[1, 2]),
+            true,
+        )
+        .unwrap();
+        set_price_feeds_flag(deps.as_mut().storage, &DENIED_PRICE_FEEDS, &ids(&[3]), true).unwrap();
+        assert_eq!(
+            query_price_feed_ids(deps.as_ref(), &FROZEN_PRICE_FEEDS, None, None)
+                .unwrap()
+                .ids,
+            ids(&[1, 2])
+        );
+
+        // Clearing a flag that is not set is a no-op.
+        set_price_feeds_flag(
+            deps.as_mut().storage,
+            &FROZEN_PRICE_FEEDS,
+            &ids(&[1, 3]),
+            false,
+        )
+        .unwrap();
+        assert_eq!(
+            query_price_feed_ids(deps.as_ref(), &FROZEN_PRICE_FEEDS, None, None)
+                .unwrap()
+                .ids,
+            ids(&[2])
+        );
+        assert_eq!(
+            query_price_feed_ids(deps.as_ref(), &DENIED_PRICE_FEEDS, None, None)
+                .unwrap()
+                .ids,
+            ids(&[3])
+        );
+    }
+
+    #[test]
+    fn test_query_price_feed_ids_pagination() {
+        let mut deps = mock_dependencies();
+        let all = (1..=40).collect::<Vec<u8>>();
+        set_price_feeds_flag(deps.as_mut().storage, &FROZEN_PRICE_FEEDS, &ids(&all), true).unwrap();
+
+        let page = |start_after: Option<u8>, limit: Option<u32>| {
+            query_price_feed_ids(
+                deps.as_ref(),
+                &FROZEN_PRICE_FEEDS,
+                start_after.map(|id| PriceIdentifier::new([id; 32])),
+                limit,
+            )
+            .unwrap()
+            .ids
+        };
+
+        assert_eq!(page(None, None), ids(&all[..DEFAULT_LIMIT as usize]));
+        assert_eq!(page(None, Some(100)), ids(&all[..MAX_LIMIT as usize]));
+        assert_eq!(page(Some(10), Some(3)), ids(&[11, 12, 13]));
+        assert_eq!(page(Some(38), None), ids(&[39, 40]));
+        assert_eq!(page(Some(40), None), ids(&[]));
+    }
+
+    #[test]
+    fn test_price_feed_skip_reason() {
+        let mut deps = mock_dependencies();
+        let [frozen, denied, both, enabled] = [1, 2, 3, 4].map(|id| PriceIdentifier::new([id; 32]));
+        set_price_feeds_flag(
+            deps.as_mut().storage,
+            &FROZEN_PRICE_FEEDS,
+            &[frozen, both],
+            true,
+        )
+        .unwrap();
+        set_price_feeds_flag(
+            deps.as_mut().storage,
+            &DENIED_PRICE_FEEDS,
+            &[denied, both],
+            true,
+        )
+        .unwrap();
+
+        let deps = deps.as_ref();
+        assert_eq!(
+            price_feed_skip_reason(&deps, &frozen),
+            Some(SkipReason::Frozen)
+        );
+        assert_eq!(
+            price_feed_skip_reason(&deps, &denied),
+            Some(SkipReason::Denied)
+        );
+        // Denying a feed takes precedence over freezing it.
+        assert_eq!(
+            price_feed_skip_reason(&deps, &both),
+            Some(SkipReason::Denied)
+        );
+        assert!(matches!(
+            ensure_price_feed_enabled(&deps, &both),
+            Err(ContractError::PriceFeedDenied {})
+        ));
+        assert_eq!(price_feed_skip_reason(&deps, &enabled), None);
+        assert!(ensure_price_feed_enabled(&deps, &enabled).is_ok());
+    }
+
+    #[test]
+    fn test_resolve_price_feed_id() {
+        let mut deps = mock_dependencies();
+        let [deprecated, replacement, latest, unrelated] =
+            [1, 2, 3, 4].map(|id| PriceIdentifier::new([id; 32]));
+        PRICE_FEED_REDIRECTS
+            .save(deps.as_mut().storage, &deprecated.to_bytes(), &replacement)
+            .unwrap();
+        PRICE_FEED_REDIRECTS
+            .save(deps.as_mut().storage, &replacement.to_bytes(), &latest)
+            .unwrap();
+
+        // Redirects are followed for a single hop only.
+        let deps = deps.as_ref();
+        assert_eq!(
+            resolve_price_feed_id(&deps, deprecated).unwrap(),
+            replacement
+        );
+        assert_eq!(resolve_price_feed_id(&deps, replacement).unwrap(), latest);
+        assert_eq!(resolve_price_feed_id(&deps, unrelated).unwrap(), unrelated);
+
+        let response: ResolvePriceFeedIdResponse = from_json(
+            query(
+                deps,
+                mock_env(),
+                QueryMsg::ResolvePriceFeedId { id: deprecated },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            response,
+            ResolvePriceFeedIdResponse {
+                id: deprecated,
+                resolved_id: replacement,
+            }
+        );
+    }
+}
@@ -76,4 +76,60 @@ pub enum ContractError {
     /// Merkle proof is invalid
     #[error("InvalidMerkleProof")]
     InvalidMerkleProof,
+
+    /// Price is published further ahead of the block time than the configured skew allows
+    #[error("PublishTimeInFuture")]
+    PublishTimeInFuture { publish_time: i64 },
+
+    /// Price is zero or negative
+    #[error("NonPositivePrice")]
+    NonPositivePrice {},
+
+    /// Price confidence interval is wider than the configured fraction of the price
+    #[error("ConfidenceTooWide")]
+    ConfidenceTooWide {},
+
+    /// Price updates are paused by governance
+    #[error("Paused")]
+    Paused {},
+
+    /// Price feed is frozen by governance
+    #[error("PriceFeedFrozen")]
+    PriceFeedFrozen {},
+
+    /// Price feed is deny-listed by governance
+    #[error("PriceFeedDenied")]
+    PriceFeedDenied {},
+
+    /// Price feed redirect points a feed at itself
+    #[error("InvalidPriceFeedRedirect")]
+    InvalidPriceFeedRedirect {},
+
+    /// Symbol is already registered for another price feed
+    #[error("PriceFeedSymbolAlreadyExists")]
+    PriceFeedSymbolAlreadyExists {},
+
+    /// Denom has no price feed registered
+    #[error("DenomNotFound")]
+    DenomNotFound { denom: String },
+
+    /// Price arithmetic overflowed
+    #[error("PriceOverflow")]
+    PriceOverflow {},
+
+    /// Derived price feed definition is invalid
+    #[error("InvalidDerivedPriceFeed")]
+    InvalidDerivedPriceFeed {},
+
+    /// Too few fresh components to aggregate a price from
+    #[error("InsufficientAggregateComponents")]
+    InsufficientAggregateComponents {},
+
+    /// Requested more decimal places than a decimal can hold
+    #[error("InvalidDecimals")]
+    InvalidDecimals { decimals: u32 },
+
+    /// Spot price is too uncertain or too far from the EMA price
+    #[error("EmaGuardTriggered")]
+    EmaGuardTriggered {},
 }
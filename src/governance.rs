@@ -3,9 +3,12 @@ use std::io::Write;
 use byteorder::{BigEndian, ReadBytesExt, WriteBytesExt};
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::Binary;
+use pyth_sdk::PriceIdentifier;
 use pyth_wormhole_attester_sdk::ErrBox;
 
-use crate::state::PythDataSource;
+use crate::state::{
+    BasketComponent, DenomPriceFeed, DerivedPriceFeed, PriceFeedMetadata, PythDataSource,
+};
 
 const PYTH_GOVERNANCE_MAGIC: &[u8] = b"PTGM";
 
@@ -39,12 +42,65 @@ impl GovernanceModule {
 /// The action to perform to change the state of the target chain contract.
 ///
 /// Note that the order of the enum cannot be changed, as the integer representation of
-/// each field must be preserved for backward compatibility.
+/// each field must be preserved for backward compatibility. Action types from 64 upwards are
+/// specific to this contract and are not part of the upstream Pyth governance actions.
 #[cw_serde]
 #[repr(u8)]
 pub enum GovernanceAction {
     /// Set the set of authorized emitters for price update messages.
     SetDataSources { data_sources: Vec<PythDataSource> }, // 2
+    /// Set the thresholds used to reject implausible prices while parsing updates.
+    SetPriceValidation {
+        max_publish_time_skew: Option<u64>,
+        max_conf_bps: Option<u64>,
+        reject_non_positive_prices: bool,
+    }, // 64
+    /// Stop parsing price updates until an `Unpause` action is executed.
+    Pause {}, // 65
+    /// Resume parsing price updates.
+    Unpause {}, // 66
+    /// Freeze or unfreeze individual price feeds.
+    SetPriceFeedsFrozen {
+        ids: Vec<PriceIdentifier>,
+        frozen: bool,
+    }, // 67
+    /// Add or remove price feeds from the deny-list.
+    SetPriceFeedsDenied {
+        ids: Vec<PriceIdentifier>,
+        denied: bool,
+    }, // 68
+    /// Redirect queries for the deprecated feed `from` to the feed `to`, or remove the redirect
+    /// if `to` is `None`.
+    SetPriceFeedRedirect {
+        from: PriceIdentifier,
+        to: Option<PriceIdentifier>,
+    }, // 69
+    /// Register metadata for the feed `id`, or remove it from the registry if `metadata` is
+    /// `None`.
+    SetPriceFeedMetadata {
+        id: PriceIdentifier,
+        metadata: Option<PriceFeedMetadata>,
+    }, // 70
+    /// Set the price feed used to value the bank denom `denom`, or remove it if `price_feed` is
+    /// `None`.
+    SetDenomPriceFeed {
+        denom: String,
+        price_feed: Option<DenomPriceFeed>,
+    }, // 71
+    /// Serve the derived price feed `derived` under the id `id`, or remove it if `derived` is
+    /// `None`.
+    SetDerivedPriceFeed {
+        id: PriceIdentifier,
+        derived: Option<DerivedPriceFeed>,
+    }, // 72
+    /// Set the default number of confidence intervals, in basis points, that price bounds are
+    /// placed away from the price.
+    SetConfMultiplier { conf_multiplier_bps: u64 }, // 73
+    /// Set the thresholds at which guarded price queries stop trusting the spot price.
+    SetEmaGuard {
+        max_conf_bps: Option<u64>,
+        max_deviation_bps: Option<u64>,
+    }, // 74
 }
 
 #[cw_serde]
@@ -89,6 +145,88 @@ impl GovernanceInstruction {
 
                 Ok(GovernanceAction::SetDataSources { data_sources })
             }
+            64 => {
+                let max_publish_time_skew = read_optional_u64(&mut bytes)?;
+                let max_conf_bps = read_optional_u64(&mut bytes)?;
+                let reject_non_positive_prices = read_bool(&mut bytes)?;
+
+                Ok(GovernanceAction::SetPriceValidation {
+                    max_publish_time_skew,
+                    max_conf_bps,
+                    reject_non_positive_prices,
+                })
+            }
+            65 => Ok(GovernanceAction::Pause {}),
+            66 => Ok(GovernanceAction::Unpause {}),
+            67 => {
+                let ids = read_price_feed_ids(&mut bytes)?;
+                let frozen = read_bool(&mut bytes)?;
+
+                Ok(GovernanceAction::SetPriceFeedsFrozen { ids, frozen })
+            }
+            68 => {
+                let ids = read_price_feed_ids(&mut bytes)?;
+                let denied = read_bool(&mut bytes)?;
+
+                Ok(GovernanceAction::SetPriceFeedsDenied { ids, denied })
+            }
+            69 => {
+                let from = read_price_feed_id(&mut bytes)?;
+                let to = match read_bool(&mut bytes)? {
+                    true => Some(read_price_feed_id(&mut bytes)?),
+                    false => None,
+                };
+
+                Ok(GovernanceAction::SetPriceFeedRedirect { from, to })
+            }
+            70 => {
+                let id = read_price_feed_id(&mut bytes)?;
+                let metadata = match read_bool(&mut bytes)? {
+                    true => Some(PriceFeedMetadata {
+                        symbol: read_string(&mut bytes)?,
+                        asset_class: read_string(&mut bytes)?,
+                        base: read_string(&mut bytes)?,
+                        quote: read_string(&mut bytes)?,
+                        expo: bytes.read_i32::<BigEndian>()?,
+                    }),
+                    false => None,
+                };
+
+                Ok(GovernanceAction::SetPriceFeedMetadata { id, metadata })
+            }
+            71 => {
+                let denom = read_string(&mut bytes)?;
+                let price_feed = match read_bool(&mut bytes)? {
+                    true => Some(DenomPriceFeed {
+                        price_feed_id: read_price_feed_id(&mut bytes)?,
+                        decimals: bytes.read_u8()?,
+                    }),
+                    false => None,
+                };
+
+                Ok(GovernanceAction::SetDenomPriceFeed { denom, price_feed })
+            }
+            72 => {
+                let id = read_price_feed_id(&mut bytes)?;
+                let derived = match read_bool(&mut bytes)? {
+                    true => Some(read_derived_price_feed(&mut bytes)?),
+                    false => None,
+                };
+
+                Ok(GovernanceAction::SetDerivedPriceFeed { id, derived })
+            }
+            73 => Ok(GovernanceAction::SetConfMultiplier {
+                conf_multiplier_bps: bytes.read_u64::<BigEndian>()?,
+            }),
+            74 => {
+                let max_conf_bps = read_optional_u64(&mut bytes)?;
+                let max_deviation_bps = read_optional_u64(&mut bytes)?;
+
+                Ok(GovernanceAction::SetEmaGuard {
+                    max_conf_bps,
+                    max_deviation_bps,
+                })
+            }
 
             _ => Err(format!("Unknown governance action type: {action_type}",)),
         };
@@ -134,15 +272,524 @@ impl GovernanceInstruction {
                     buf.write_all(data_source.emitter.as_slice())?;
                 }
             }
+            GovernanceAction::SetPriceValidation {
+                max_publish_time_skew,
+                max_conf_bps,
+                reject_non_positive_prices,
+            } => {
+                buf.write_u8(64)?;
+                buf.write_u16::<BigEndian>(self.target_chain_id)?;
+                write_optional_u64(&mut buf, *max_publish_time_skew)?;
+                write_optional_u64(&mut buf, *max_conf_bps)?;
+                buf.write_u8(u8::from(*reject_non_positive_prices))?;
+            }
+            GovernanceAction::Pause {} => {
+                buf.write_u8(65)?;
+                buf.write_u16::<BigEndian>(self.target_chain_id)?;
+            }
+            GovernanceAction::Unpause {} => {
+                buf.write_u8(66)?;
+                buf.write_u16::<BigEndian>(self.target_chain_id)?;
+            }
+            GovernanceAction::SetPriceFeedsFrozen { ids, frozen } => {
+                buf.write_u8(67)?;
+                buf.write_u16::<BigEndian>(self.target_chain_id)?;
+                write_price_feed_ids(&mut buf, ids)?;
+                buf.write_u8(u8::from(*frozen))?;
+            }
+            GovernanceAction::SetPriceFeedsDenied { ids, denied } => {
+                buf.write_u8(68)?;
+                buf.write_u16::<BigEndian>(self.target_chain_id)?;
+                write_price_feed_ids(&mut buf, ids)?;
+                buf.write_u8(u8::from(*denied))?;
+            }
+            GovernanceAction::SetPriceFeedRedirect { from, to } => {
+                buf.write_u8(69)?;
+                buf.write_u16::<BigEndian>(self.target_chain_id)?;
+                buf.write_all(&from.to_bytes())?;
+                match to {
+                    None => buf.write_u8(0)?,
+                    Some(to) => {
+                        buf.write_u8(1)?;
+                        buf.write_all(&to.to_bytes())?;
+                    }
+                }
+            }
+            GovernanceAction::SetPriceFeedMetadata { id, metadata } => {
+                buf.write_u8(70)?;
+                buf.write_u16::<BigEndian>(self.target_chain_id)?;
+                buf.write_all(&id.to_bytes())?;
+                match metadata {
+                    None => buf.write_u8(0)?,
+                    Some(metadata) => {
+                        buf.write_u8(1)?;
+                        write_string(&mut buf, &metadata.symbol)?;
+                        write_string(&mut buf, &metadata.asset_class)?;
+                        write_string(&mut buf, &metadata.base)?;
+                        write_string(&mut buf, &metadata.quote)?;
+                        buf.write_i32::<BigEndian>(metadata.expo)?;
+                    }
+                }
+            }
+            GovernanceAction::SetDenomPriceFeed { denom, price_feed } => {
+                buf.write_u8(71)?;
+                buf.write_u16::<BigEndian>(self.target_chain_id)?;
+                write_string(&mut buf, denom)?;
+                match price_feed {
+                    None => buf.write_u8(0)?,
+                    Some(price_feed) => {
+                        buf.write_u8(1)?;
+                        buf.write_all(&price_feed.price_feed_id.to_bytes())?;
+                        buf.write_u8(price_feed.decimals)?;
+                    }
+                }
+            }
+            GovernanceAction::SetDerivedPriceFeed { id, derived } => {
+                buf.write_u8(72)?;
+                buf.write_u16::<BigEndian>(self.target_chain_id)?;
+                buf.write_all(&id.to_bytes())?;
+                match derived {
+                    None => buf.write_u8(0)?,
+                    Some(derived) => {
+                        buf.write_u8(1)?;
+                        write_derived_price_feed(&mut buf, derived)?;
+                    }
+                }
+            }
+            GovernanceAction::SetConfMultiplier {
+                conf_multiplier_bps,
+            } => {
+                buf.write_u8(73)?;
+                buf.write_u16::<BigEndian>(self.target_chain_id)?;
+                buf.write_u64::<BigEndian>(*conf_multiplier_bps)?;
+            }
+            GovernanceAction::SetEmaGuard {
+                max_conf_bps,
+                max_deviation_bps,
+            } => {
+                buf.write_u8(74)?;
+                buf.write_u16::<BigEndian>(self.target_chain_id)?;
+                write_optional_u64(&mut buf, *max_conf_bps)?;
+                write_optional_u64(&mut buf, *max_deviation_bps)?;
+            }
         }
 
         Ok(buf)
     }
 }
 
+/// Optional values are encoded as a presence byte (0 or 1) followed by the value if present.
+fn read_optional_u64(bytes: &mut impl ReadBytesExt) -> Result<Option<u64>, ErrBox> {
+    match bytes.read_u8()? {
+        0 => Ok(None),
+        1 => Ok(Some(bytes.read_u64::<BigEndian>()?)),
+        flag => Err(format!("Invalid option flag: {flag}",).into()),
+    }
+}
+
+fn write_optional_u64(buf: &mut Vec<u8>, value: Option<u64>) -> Result<(), ErrBox> {
+    match value {
+        None => buf.write_u8(0)?,
+        Some(value) => {
+            buf.write_u8(1)?;
+            buf.write_u64::<BigEndian>(value)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn read_bool(bytes: &mut impl ReadBytesExt) -> Result<bool, ErrBox> {
+    match bytes.read_u8()? {
+        0 => Ok(false),
+        1 => Ok(true),
+        flag => Err(format!("Invalid bool flag: {flag}",).into()),
+    }
+}
+
+/// Price feed id lists are encoded as a one byte count followed by the 32 byte ids.
+fn read_price_feed_ids(bytes: &mut impl ReadBytesExt) -> Result<Vec<PriceIdentifier>, ErrBox> {
+    let num_ids = bytes.read_u8()?;
+    let mut ids = vec![];
+    for _ in 0..num_ids {
+        ids.push(read_price_feed_id(bytes)?);
+    }
+
+    Ok(ids)
+}
+
+fn write_price_feed_ids(buf: &mut Vec<u8>, ids: &[PriceIdentifier]) -> Result<(), ErrBox> {
+    buf.write_u8(u8::try_from(ids.len())?)?;
+    for id in ids {
+        buf.write_all(&id.to_bytes())?;
+    }
+
+    Ok(())
+}
+
+fn read_price_feed_id(bytes: &mut impl ReadBytesExt) -> Result<PriceIdentifier, ErrBox> {
+    let mut id: [u8; 32] = [0; 32];
+    bytes.read_exact(&mut id)?;
+
+    Ok(PriceIdentifier::new(id))
+}
+
+/// Derived price feeds are encoded as a one byte kind followed by the fields of that kind.
+fn read_derived_price_feed(bytes: &mut impl ReadBytesExt) -> Result<DerivedPriceFeed, ErrBox> {
+    match bytes.read_u8()? {
+        0 => Ok(DerivedPriceFeed::CrossRate {
+            base: read_price_feed_id(bytes)?,
+            quote: read_price_feed_id(bytes)?,
+            expo: bytes.read_i32::<BigEndian>()?,
+        }),
+        1 => Ok(DerivedPriceFeed::Inverse {
+            feed: read_price_feed_id(bytes)?,
+            expo: bytes.read_i32::<BigEndian>()?,
+        }),
+        2 => {
+            let num_components = bytes.read_u8()?;
+            let mut components = vec![];
+            for _ in 0..num_components {
+                components.push(BasketComponent {
+                    id: read_price_feed_id(bytes)?,
+                    quantity: bytes.read_i64::<BigEndian>()?,
+                    quantity_expo: bytes.read_i32::<BigEndian>()?,
+                });
+            }
+            let expo = bytes.read_i32::<BigEndian>()?;
+
+            Ok(DerivedPriceFeed::Basket { components, expo })
+        }
+        3 => Ok(DerivedPriceFeed::Median {
+            components: read_price_feed_ids(bytes)?,
+            max_age: bytes.read_u64::<BigEndian>()?,
+            max_deviation_bps: bytes.read_u64::<BigEndian>()?,
+            min_components: bytes.read_u8()?,
+        }),
+        kind => Err(format!("Unknown derived price feed kind: {kind}",).into()),
+    }
+}
+
+fn write_derived_price_feed(buf: &mut Vec<u8>, derived: &DerivedPriceFeed) -> Result<(), ErrBox> {
+    match derived {
+        DerivedPriceFeed::CrossRate { base, quote, expo } => {
+            buf.write_u8(0)?;
+            buf.write_all(&base.to_bytes())?;
+            buf.write_all(&quote.to_bytes())?;
+            buf.write_i32::<BigEndian>(*expo)?;
+        }
+        DerivedPriceFeed::Inverse { feed, expo } => {
+            buf.write_u8(1)?;
+            buf.write_all(&feed.to_bytes())?;
+            buf.write_i32::<BigEndian>(*expo)?;
+        }
+        DerivedPriceFeed::Basket { components, expo } => {
+            buf.write_u8(2)?;
+            buf.write_u8(u8::try_from(components.len())?)?;
+            for component in components {
+                buf.write_all(&component.id.to_bytes())?;
+                buf.write_i64::<BigEndian>(component.quantity)?;
+                buf.write_i32::<BigEndian>(component.quantity_expo)?;
+            }
+            buf.write_i32::<BigEndian>(*expo)?;
+        }
+        DerivedPriceFeed::Median {
+            components,
+            max_age,
+            max_deviation_bps,
+            min_components,
+        } => {
+            buf.write_u8(3)?;
+            write_price_feed_ids(buf, components)?;
+            buf.write_u64::<BigEndian>(*max_age)?;
+            buf.write_u64::<BigEndian>(*max_deviation_bps)?;
+            buf.write_u8(*min_components)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Strings are encoded as a one byte length followed by that many bytes of UTF-8.
+fn read_string(bytes: &mut impl ReadBytesExt) -> Result<String, ErrBox> {
+    let len = bytes.read_u8()?;
+    let mut string = vec![0u8; usize::from(len)];
+    bytes.read_exact(&mut string)?;
+
+    Ok(String::from_utf8(string)?)
+}
+
+fn write_string(buf: &mut Vec<u8>, string: &str) -> Result<(), ErrBox> {
+    buf.write_u8(u8::try_from(string.len())?)?;
+    buf.write_all(string.as_bytes())?;
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod test {
+    use super::*;
+
+    fn id(byte: u8) -> PriceIdentifier {
+        PriceIdentifier::new([byte; 32])
+    }
+
+    fn instruction(action: GovernanceAction) -> GovernanceInstruction {
+        GovernanceInstruction {
+            module: GovernanceModule::Target,
+            action,
+            target_chain_id: 18,
+        }
+    }
+
+    fn assert_round_trip(action: GovernanceAction) {
+        let instruction = instruction(action);
+        let bytes = instruction.serialize().unwrap();
+
+        assert_eq!(
+            GovernanceInstruction::deserialize(bytes.as_slice()).unwrap(),
+            instruction
+        );
+    }
+
+    #[test]
+    fn test_payload_wrong_size() {
+        let bytes = instruction(GovernanceAction::SetPriceFeedsFrozen {
+            ids: vec![id(1), id(2)],
+            frozen: true,
+        })
+        .serialize()
+        .unwrap();
+
+        let mut long = bytes.clone();
+        long.push(0);
+        assert!(GovernanceInstruction::deserialize(long.as_slice()).is_err());
+        assert!(GovernanceInstruction::deserialize(&bytes[..bytes.len() - 1]).is_err());
+    }
+
+    #[test]
+    fn test_set_price_validation() {
+        let action = GovernanceAction::SetPriceValidation {
+            max_publish_time_skew: Some(60),
+            max_conf_bps: None,
+            reject_non_positive_prices: true,
+        };
+
+        assert_eq!(
+            instruction(action.clone()).serialize().unwrap(),
+            [
+                b"PTGM".as_slice(),
+                &[1, 64, 0, 18],
+                &[1, 0, 0, 0, 0, 0, 0, 0, 60],
+                &[0],
+                &[1],
+            ]
+            .concat()
+        );
+        assert_round_trip(action);
+        assert_round_trip(GovernanceAction::SetPriceValidation {
+            max_publish_time_skew: None,
+            max_conf_bps: Some(100),
+            reject_non_positive_prices: false,
+        });
+    }
+
+    #[test]
+    fn test_invalid_flag() {
+        let mut bytes = instruction(GovernanceAction::SetPriceValidation {
+            max_publish_time_skew: None,
+            max_conf_bps: None,
+            reject_non_positive_prices: false,
+        })
+        .serialize()
+        .unwrap();
+        *bytes.last_mut().unwrap() = 2;
+
+        assert!(GovernanceInstruction::deserialize(bytes.as_slice()).is_err());
+    }
+
+    #[test]
+    fn test_pause_unpause() {
+        assert_eq!(
+            instruction(GovernanceAction::Pause {}).serialize().unwrap(),
+            [b"PTGM".as_slice(), &[1, 65, 0, 18]].concat()
+        );
+        assert_round_trip(GovernanceAction::Pause {});
+        assert_round_trip(GovernanceAction::Unpause {});
+    }
+
+    #[test]
+    fn test_set_price_feeds_frozen_denied() {
+        assert_round_trip(GovernanceAction::SetPriceFeedsFrozen {
+            ids: vec![id(1), id(2)],
+            frozen: true,
+        });
+        assert_round_trip(GovernanceAction::SetPriceFeedsFrozen {
+            ids: vec![],
+            frozen: false,
+        });
+        assert_round_trip(GovernanceAction::SetPriceFeedsDenied {
+            ids: vec![id(3)],
+            denied: true,
+        });
+        assert_round_trip(GovernanceAction::SetPriceFeedsDenied {
+            ids: vec![id(3)],
+            denied: false,
+        });
+    }
+
+    #[test]
+    fn test_set_price_feed_redirect() {
+        assert_round_trip(GovernanceAction::SetPriceFeedRedirect {
+            from: id(1),
+            to: Some(id(2)),
+        });
+        assert_round_trip(GovernanceAction::SetPriceFeedRedirect {
+            from: id(1),
+            to: None,
+        });
+    }
 
     #[test]
-    fn test_payload_wrong_size() {}
+    fn test_set_price_feed_metadata() {
+        assert_round_trip(GovernanceAction::SetPriceFeedMetadata {
+            id: id(1),
+            metadata: Some(PriceFeedMetadata {
+                symbol: "Crypto.ATOM/USD".to_string(),
+                asset_class: "Crypto".to_string(),
+                base: "ATOM".to_string(),
+                quote: "USD".to_string(),
+                expo: -8,
+            }),
+        });
+        assert_round_trip(GovernanceAction::SetPriceFeedMetadata {
+            id: id(1),
+            metadata: None,
+        });
+    }
+
+    #[test]
+    fn test_set_denom_price_feed() {
+        assert_round_trip(GovernanceAction::SetDenomPriceFeed {
+            denom: "uatom".to_string(),
+            price_feed: Some(DenomPriceFeed {
+                price_feed_id: id(1),
+                decimals: 6,
+            }),
+        });
+        assert_round_trip(GovernanceAction::SetDenomPriceFeed {
+            denom: "ibc/27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2"
+                .to_string(),
+            price_feed: None,
+        });
+    }
+
+    #[test]
+    fn test_set_derived_price_feed() {
+        let inverse = GovernanceAction::SetDerivedPriceFeed {
+            id: id(9),
+            derived: Some(DerivedPriceFeed::Inverse {
+                feed: id(1),
+                expo: -10,
+            }),
+        };
+        assert_eq!(
+            instruction(inverse.clone()).serialize().unwrap(),
+            [
+                b"PTGM".as_slice(),
+                &[1, 72, 0, 18],
+                &[9; 32],
+                &[1, 1],
+                &[1; 32],
+                &(-10i32).to_be_bytes(),
+            ]
+            .concat()
+        );
+        assert_round_trip(inverse);
+
+        assert_round_trip(GovernanceAction::SetDerivedPriceFeed {
+            id: id(9),
+            derived: Some(DerivedPriceFeed::CrossRate {
+                base: id(1),
+                quote: id(2),
+                expo: -8,
+            }),
+        });
+
+        assert_round_trip(GovernanceAction::SetDerivedPriceFeed {
+            id: id(9),
+            derived: None,
+        });
+    }
+
+    #[test]
+    fn test_set_basket_price_feed() {
+        assert_round_trip(GovernanceAction::SetDerivedPriceFeed {
+            id: id(9),
+            derived: Some(DerivedPriceFeed::Basket {
+                components: vec![
+                    BasketComponent {
+                        id: id(1),
+                        quantity: 15,
+                        quantity_expo: -1,
+                    },
+                    BasketComponent {
+                        id: id(2),
+                        quantity: -3,
+                        quantity_expo: 0,
+                    },
+                ],
+                expo: -6,
+            }),
+        });
+    }
+
+    #[test]
+    fn test_set_median_price_feed() {
+        assert_round_trip(GovernanceAction::SetDerivedPriceFeed {
+            id: id(9),
+            derived: Some(DerivedPriceFeed::Median {
+                components: vec![id(1), id(2), id(3)],
+                max_age: 60,
+                max_deviation_bps: 200,
+                min_components: 2,
+            }),
+        });
+    }
+
+    #[test]
+    fn test_unknown_derived_price_feed_kind() {
+        let mut bytes = instruction(GovernanceAction::SetDerivedPriceFeed {
+            id: id(9),
+            derived: Some(DerivedPriceFeed::Inverse {
+                feed: id(1),
+                expo: -8,
+            }),
+        })
+        .serialize()
+        .unwrap();
+        // The kind follows the header, the id and the presence flag.
+        bytes[8 + 32 + 1] = 4;
+
+        assert!(GovernanceInstruction::deserialize(bytes.as_slice()).is_err());
+    }
+
+    #[test]
+    fn test_set_conf_multiplier() {
+        assert_round_trip(GovernanceAction::SetConfMultiplier {
+            conf_multiplier_bps: 25_000,
+        });
+    }
+
+    #[test]
+    fn test_set_ema_guard() {
+        assert_round_trip(GovernanceAction::SetEmaGuard {
+            max_conf_bps: Some(200),
+            max_deviation_bps: Some(500),
+        });
+        assert_round_trip(GovernanceAction::SetEmaGuard {
+            max_conf_bps: None,
+            max_deviation_bps: None,
+        });
+    }
 }
@@ -1,6 +1,7 @@
 use byteorder::BigEndian;
 use cosmwasm_std::{
-    to_json_binary, Binary, Deps, Env, QueryRequest, StdError, StdResult, WasmQuery,
+    to_json_binary, Binary, Decimal256, Deps, Env, Int256, QueryRequest, SignedDecimal256,
+    StdError, StdResult, Uint256, WasmQuery,
 };
 use cw_mini_wormhole::{msg::QueryMsg as WormholeQueryMsg, state::ParsedVAA};
 use pyth_sdk::{Price, PriceFeed, PriceIdentifier};
@@ -19,6 +20,7 @@ use pythnet_sdk::{
 };
 
 use crate::{
+    msg::NormalizedPrice,
     state::{ConfigInfo, PythDataSource, CONFIG},
     ContractError,
 };
@@ -72,7 +74,11 @@ pub fn parse_and_verify_vaa(deps: Deps, block_time: u64, data: Binary) -> StdRes
     Ok(vaa)
 }
 
-pub fn parse_update(deps: &Deps, env: &Env, data: &Binary) -> StdResult<Vec<PriceFeed>> {
+pub fn parse_update(
+    deps: &Deps,
+    env: &Env,
+    data: &Binary,
+) -> Result<Vec<PriceFeed>, ContractError> {
     let header = data.get(0..4);
     let feeds = if header == Some(PYTHNET_ACCUMULATOR_UPDATE_MAGIC.as_slice()) {
         parse_accumulator(deps, env, data)?
@@ -83,7 +89,7 @@ pub fn parse_update(deps: &Deps, env: &Env, data: &Binary) -> StdResult<Vec<Pric
     Ok(feeds)
 }
 
-fn parse_accumulator(deps: &Deps, env: &Env, data: &[u8]) -> StdResult<Vec<PriceFeed>> {
+fn parse_accumulator(deps: &Deps, env: &Env, data: &[u8]) -> Result<Vec<PriceFeed>, ContractError> {
     let update_data = AccumulatorUpdateData::try_from_slice(data)
         .map_err(|_| StdError::generic_err("Invalid accumalator payload"))?;
 
@@ -107,7 +113,7 @@ fn parse_accumulator(deps: &Deps, env: &Env, data: &[u8]) -> StdResult<Vec<Price
             for update in updates {
                 let message_vec = Vec::from(update.message);
                 if !root.check(update.proof, &message_vec) {
-                    return Err(StdError::generic_err("Invalid merkly proof"));
+                    Err(StdError::generic_err("Invalid merkly proof"))?
                 }
 
                 let msg = from_slice::<BigEndian, Message>(&message_vec)
@@ -141,7 +147,11 @@ fn parse_accumulator(deps: &Deps, env: &Env, data: &[u8]) -> StdResult<Vec<Price
 }
 
 /// Update the on-chain storage for any new price updates provided in `batch_attestation`.
-fn parse_batch_attestation(deps: &Deps, env: &Env, data: &Binary) -> StdResult<Vec<PriceFeed>> {
+fn parse_batch_attestation(
+    deps: &Deps,
+    env: &Env,
+    data: &Binary,
+) -> Result<Vec<PriceFeed>, ContractError> {
     let vaa = parse_and_verify_vaa(*deps, env.block.time.seconds(), data.clone())?;
     let config = CONFIG.load(deps.storage)?;
 
@@ -195,3 +205,314 @@ fn create_price_feed_from_price_attestation(price_attestation: &PriceAttestation
         ),
     }
 }
+
+/// Reject prices that are dated too far ahead of the block time, are not positive, or carry a
+/// confidence interval wider than the configured fraction of the price. Each check is only
+/// applied once governance has enabled it.
+///
+/// This is applied to the price feeds a query uses, not to every feed in an update, so that one
+/// implausible feed does not block the other feeds published in the same update.
+pub fn validate_price(config: &ConfigInfo, env: &Env, price: &Price) -> Result<(), ContractError> {
+    if let Some(max_skew) = config.max_publish_time_skew {
+        let latest_allowed = i128::from(env.block.time.seconds()) + i128::from(max_skew);
+        if i128::from(price.publish_time) > latest_allowed {
+            return Err(ContractError::PublishTimeInFuture {
+                publish_time: price.publish_time,
+            });
+        }
+    }
+
+    if config.reject_non_positive_prices && price.price <= 0 {
+        return Err(ContractError::NonPositivePrice {});
+    }
+
+    if let Some(max_conf_bps) = config.max_conf_bps {
+        let max_conf = u128::from(price.price.unsigned_abs()) * u128::from(max_conf_bps);
+        if u128::from(price.conf) * 10_000 > max_conf {
+            return Err(ContractError::ConfidenceTooWide {});
+        }
+    }
+
+    Ok(())
+}
+
+/// Convert `value * 10^expo` into a [Decimal256]. Digits beyond the 18 decimal places of
+/// [Decimal256] are truncated.
+pub fn to_decimal256(value: u64, expo: i32) -> Result<Decimal256, ContractError> {
+    let decimal = if expo >= 0 {
+        Uint256::from(10u8)
+            .checked_pow(expo.unsigned_abs())
+            .and_then(|factor| factor.checked_mul(Uint256::from(value)))
+            .ok()
+            .and_then(|atomics| Decimal256::from_atomics(atomics, 0).ok())
+    } else {
+        Decimal256::from_atomics(Uint256::from(value), expo.unsigned_abs()).ok()
+    };
+
+    decimal.ok_or(ContractError::PriceOverflow {})
+}
+
+/// Convert `value * 10^expo` into a [SignedDecimal256]. Digits beyond the 18 decimal places of
+/// [SignedDecimal256] are truncated towards zero.
+pub fn to_signed_decimal256(value: i64, expo: i32) -> Result<SignedDecimal256, ContractError> {
+    let decimal = if expo >= 0 {
+        Int256::from(10u8)
+            .checked_pow(expo.unsigned_abs())
+            .and_then(|factor| factor.checked_mul(Int256::from(value)))
+            .ok()
+            .and_then(|atomics| SignedDecimal256::from_atomics(atomics, 0).ok())
+    } else {
+        SignedDecimal256::from_atomics(Int256::from(value), expo.unsigned_abs()).ok()
+    };
+
+    decimal.ok_or(ContractError::PriceOverflow {})
+}
+
+/// Express `price` as decimals, rounded towards zero to `decimals` decimal places.
+pub fn normalize_price(price: &Price, decimals: u32) -> Result<NormalizedPrice, ContractError> {
+    if decimals > SignedDecimal256::DECIMAL_PLACES {
+        return Err(ContractError::InvalidDecimals { decimals });
+    }
+
+    let dropped_places = SignedDecimal256::DECIMAL_PLACES - decimals;
+    let signed_factor = Int256::from(10u8).pow(dropped_places);
+    let factor = Uint256::from(10u8).pow(dropped_places);
+
+    let value = to_signed_decimal256(price.price, price.expo)?;
+    let conf = to_decimal256(price.conf, price.expo)?;
+
+    Ok(NormalizedPrice {
+        price: SignedDecimal256::new(value.atomics() / signed_factor * signed_factor),
+        conf: Decimal256::new(conf.atomics() / factor * factor),
+        publish_time: price.publish_time,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+    use std::str::FromStr;
+
+    use cosmwasm_std::{testing::mock_env, Addr, Timestamp};
+
+    use super::*;
+
+    const BLOCK_TIME: i64 = 1_700_000_000;
+
+    fn price(price: i64, conf: u64, expo: i32) -> Price {
+        Price {
+            price,
+            conf,
+            expo,
+            publish_time: BLOCK_TIME,
+        }
+    }
+
+    fn decimal(value: &str) -> Decimal256 {
+        Decimal256::from_str(value).unwrap()
+    }
+
+    fn signed_decimal(value: &str) -> SignedDecimal256 {
+        SignedDecimal256::from_str(value).unwrap()
+    }
+
+    fn config() -> ConfigInfo {
+        ConfigInfo {
+            wormhole_contract: Addr::unchecked("wormhole"),
+            data_sources: HashSet::new(),
+            governance_source: PythDataSource {
+                emitter: Binary::from([0; 32]),
+                chain_id: 1,
+            },
+            governance_source_index: 0,
+            governance_sequence_number: 0,
+            chain_id: 18,
+            max_publish_time_skew: None,
+            max_conf_bps: None,
+            reject_non_positive_prices: false,
+            paused: false,
+            conf_multiplier: Decimal256::one(),
+            ema_guard_max_conf_bps: None,
+            ema_guard_max_deviation_bps: None,
+        }
+    }
+
+    fn env() -> Env {
+        let mut env = mock_env();
+        env.block.time = Timestamp::from_seconds(BLOCK_TIME as u64);
+        env
+    }
+
+    fn published_at(publish_time: i64, price: Price) -> Price {
+        Price {
+            publish_time,
+            ..price
+        }
+    }
+
+    #[test]
+    fn test_validate_price_disabled() {
+        let config = config();
+
+        for unchecked in [
+            published_at(BLOCK_TIME + 3_600, price(723_000_000, 0, -8)),
+            price(0, 0, -8),
+            price(-723_000_000, 0, -8),
+            price(723_000_000, 723_000_000, -8),
+        ] {
+            assert!(validate_price(&config, &env(), &unchecked).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_validate_price_publish_time_skew() {
+        let config = ConfigInfo {
+            max_publish_time_skew: Some(10),
+            ..config()
+        };
+        let atom = price(723_000_000, 0, -8);
+
+        assert!(validate_price(&config, &env(), &published_at(BLOCK_TIME - 60, atom)).is_ok());
+        assert!(validate_price(&config, &env(), &published_at(BLOCK_TIME + 10, atom)).is_ok());
+        assert!(matches!(
+            validate_price(&config, &env(), &published_at(BLOCK_TIME + 11, atom)),
+            Err(ContractError::PublishTimeInFuture { publish_time })
+                if publish_time == BLOCK_TIME + 11
+        ));
+    }
+
+    #[test]
+    fn test_validate_price_non_positive() {
+        let config = ConfigInfo {
+            reject_non_positive_prices: true,
+            ..config()
+        };
+
+        assert!(validate_price(&config, &env(), &price(1, 0, -8)).is_ok());
+        for non_positive in [price(0, 0, -8), price(-723_000_000, 0, -8)] {
+            assert!(matches!(
+                validate_price(&config, &env(), &non_positive),
+                Err(ContractError::NonPositivePrice {})
+            ));
+        }
+    }
+
+    #[test]
+    fn test_validate_price_conf() {
+        let config = ConfigInfo {
+            max_conf_bps: Some(100),
+            ..config()
+        };
+
+        // 1% of the absolute price is allowed, whatever its sign.
+        for (value, conf) in [(10_000, 100), (-10_000, 100), (0, 0)] {
+            assert!(validate_price(&config, &env(), &price(value, conf, -8)).is_ok());
+        }
+        for (value, conf) in [(10_000, 101), (-10_000, 101), (0, 1)] {
+            assert!(matches!(
+                validate_price(&config, &env(), &price(value, conf, -8)),
+                Err(ContractError::ConfidenceTooWide {})
+            ));
+        }
+    }
+
+    #[test]
+    fn test_to_decimal256() {
+        assert_eq!(to_decimal256(723_000_000, -8).unwrap(), decimal("7.23"));
+        assert_eq!(to_decimal256(5, 3).unwrap(), decimal("5000"));
+        assert_eq!(to_decimal256(5, 0).unwrap(), decimal("5"));
+        // Digits beyond 18 decimal places are truncated.
+        assert_eq!(
+            to_decimal256(123_456_789, -20).unwrap(),
+            decimal("0.000000000001234567")
+        );
+        assert!(matches!(
+            to_decimal256(u64::MAX, 100),
+            Err(ContractError::PriceOverflow {})
+        ));
+    }
+
+    #[test]
+    fn test_to_signed_decimal256() {
+        assert_eq!(
+            to_signed_decimal256(-723_000_000, -8).unwrap(),
+            signed_decimal("-7.23")
+        );
+        assert_eq!(
+            to_signed_decimal256(-5, 3).unwrap(),
+            signed_decimal("-5000")
+        );
+        // Digits beyond 18 decimal places are truncated towards zero.
+        assert_eq!(
+            to_signed_decimal256(-123_456_789, -20).unwrap(),
+            signed_decimal("-0.000000000001234567")
+        );
+        assert!(matches!(
+            to_signed_decimal256(i64::MIN, 100),
+            Err(ContractError::PriceOverflow {})
+        ));
+    }
+
+    #[test]
+    fn test_normalize_price() {
+        let atom = price(723_456_789, 1_234_567, -8);
+
+        assert_eq!(
+            normalize_price(&atom, 18).unwrap(),
+            NormalizedPrice {
+                price: signed_decimal("7.23456789"),
+                conf: decimal("0.01234567"),
+                publish_time: 1_700_000_000,
+            }
+        );
+        assert_eq!(
+            normalize_price(&atom, 2).unwrap(),
+            NormalizedPrice {
+                price: signed_decimal("7.23"),
+                conf: decimal("0.01"),
+                publish_time: 1_700_000_000,
+            }
+        );
+        assert_eq!(
+            normalize_price(&atom, 0).unwrap(),
+            NormalizedPrice {
+                price: signed_decimal("7"),
+                conf: decimal("0"),
+                publish_time: 1_700_000_000,
+            }
+        );
+    }
+
+    #[test]
+    fn test_normalize_negative_price() {
+        // Negative prices are truncated towards zero, not rounded down.
+        assert_eq!(
+            normalize_price(&price(-723_456_789, 1_234_567, -8), 2).unwrap(),
+            NormalizedPrice {
+                price: signed_decimal("-7.23"),
+                conf: decimal("0.01"),
+                publish_time: 1_700_000_000,
+            }
+        );
+    }
+
+    #[test]
+    fn test_normalize_positive_expo() {
+        assert_eq!(
+            normalize_price(&price(12, 3, 2), 0).unwrap(),
+            NormalizedPrice {
+                price: signed_decimal("1200"),
+                conf: decimal("300"),
+                publish_time: 1_700_000_000,
+            }
+        );
+    }
+
+    #[test]
+    fn test_normalize_too_many_decimals() {
+        assert!(matches!(
+            normalize_price(&price(723_456_789, 1_234_567, -8), 19),
+            Err(ContractError::InvalidDecimals { decimals: 19 })
+        ));
+    }
+}
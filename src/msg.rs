@@ -1,8 +1,8 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::Binary;
+use cosmwasm_std::{Binary, Coin, Decimal256, SignedDecimal256};
 use pyth_sdk::{Price, PriceFeed, PriceIdentifier, UnixTimestamp};
 
-use crate::state::PythDataSource;
+use crate::state::{DenomPriceFeed, DerivedPriceFeed, PriceFeedMetadata, PythDataSource};
 
 type HumanAddr = String;
 
@@ -36,6 +36,7 @@ pub enum QueryMsg {
         price_feed_ids: Vec<PriceIdentifier>,
         min_publish_time: UnixTimestamp,
         max_publish_time: UnixTimestamp,
+        normalize: Option<PriceNormalization>,
     },
 
     #[returns(ParseSinglePriceFeedResponse)]
@@ -44,14 +45,210 @@ pub enum QueryMsg {
         price_feed_id: PriceIdentifier,
         min_publish_time: UnixTimestamp,
         max_publish_time: UnixTimestamp,
+        normalize: Option<PriceNormalization>,
+    },
+
+    #[returns(PriceFeedIdsResponse)]
+    FrozenPriceFeeds {
+        start_after: Option<PriceIdentifier>,
+        limit: Option<u32>,
+    },
+
+    #[returns(PriceFeedIdsResponse)]
+    DeniedPriceFeeds {
+        start_after: Option<PriceIdentifier>,
+        limit: Option<u32>,
+    },
+
+    #[returns(ResolvePriceFeedIdResponse)]
+    ResolvePriceFeedId { id: PriceIdentifier },
+
+    #[returns(RegisteredPriceFeed)]
+    PriceFeedMetadata { id: PriceIdentifier },
+
+    #[returns(RegisteredPriceFeed)]
+    PriceFeedBySymbol { symbol: String },
+
+    #[returns(PriceFeedRegistryResponse)]
+    PriceFeedRegistry {
+        start_after: Option<PriceIdentifier>,
+        limit: Option<u32>,
+    },
+
+    #[returns(DerivedPriceFeed)]
+    DerivedPriceFeed { id: PriceIdentifier },
+
+    #[returns(DenomPriceFeed)]
+    DenomPriceFeed { denom: String },
+
+    /// The price of a feed taken from `update_data`, with bounds `conf_multiplier` confidence
+    /// intervals below and above it. `conf_multiplier` defaults to the value set by governance.
+    #[returns(PriceBoundsResponse)]
+    PriceBounds {
+        update_data: Vec<Binary>,
+        price_feed_id: PriceIdentifier,
+        min_publish_time: UnixTimestamp,
+        max_publish_time: UnixTimestamp,
+        conf_multiplier: Option<Decimal256>,
+    },
+
+    /// The spot price of a feed taken from `update_data`, unless its confidence or its deviation
+    /// from the EMA price exceed the thresholds set by governance. In that case, `mode` decides
+    /// whether the EMA price is returned or the query fails.
+    #[returns(GuardedPriceResponse)]
+    GuardedPrice {
+        update_data: Vec<Binary>,
+        price_feed_id: PriceIdentifier,
+        min_publish_time: UnixTimestamp,
+        max_publish_time: UnixTimestamp,
+        mode: EmaGuardMode,
+    },
+
+    /// Value `coins` in units of the `quote` denom, or in the quote currency of their price feeds
+    /// (usually USD) if `quote` is not set. Prices are taken from `update_data`.
+    #[returns(ValueOfResponse)]
+    ValueOf {
+        update_data: Vec<Binary>,
+        coins: Vec<Coin>,
+        quote: Option<String>,
+        min_publish_time: UnixTimestamp,
+        max_publish_time: UnixTimestamp,
     },
 }
 
 #[cw_serde]
 pub struct ParsePriceFeedsResponse {
     pub price_feeds: Vec<PriceFeed>,
+    /// Requested feeds that were not parsed because governance disabled them.
+    pub skipped_price_feeds: Vec<SkippedPriceFeed>,
+    /// Requested feeds that were resolved to a replacement feed.
+    pub redirects: Vec<PriceFeedRedirect>,
+    /// Requested aggregate feeds, with the components their prices were aggregated from.
+    pub aggregates: Vec<AggregatedPriceFeed>,
+    /// `price_feeds` as decimals, if normalization was requested.
+    pub normalized_price_feeds: Option<Vec<NormalizedPriceFeed>>,
+}
+
+/// Requests prices as decimals in addition to the raw [Price]s.
+#[cw_serde]
+pub struct PriceNormalization {
+    /// Round values towards zero to this many decimal places, at most 18. Defaults to 18.
+    pub decimals: Option<u32>,
+}
+
+/// A [Price] expressed as decimals instead of an integer and an exponent.
+#[cw_serde]
+pub struct NormalizedPrice {
+    pub price: SignedDecimal256,
+    pub conf: Decimal256,
+    pub publish_time: UnixTimestamp,
+}
+
+#[cw_serde]
+pub struct NormalizedPriceFeed {
+    pub id: PriceIdentifier,
+    pub price: NormalizedPrice,
+    pub ema_price: NormalizedPrice,
+}
+
+#[cw_serde]
+pub struct AggregatedPriceFeed {
+    pub id: PriceIdentifier,
+    pub components: Vec<PriceIdentifier>,
+}
+
+/// A deprecated price feed id `from` that is served by the feed `to`.
+#[cw_serde]
+pub struct PriceFeedRedirect {
+    pub from: PriceIdentifier,
+    pub to: PriceIdentifier,
+}
+
+#[cw_serde]
+pub struct SkippedPriceFeed {
+    pub id: PriceIdentifier,
+    pub reason: SkipReason,
+}
+
+#[cw_serde]
+pub enum SkipReason {
+    Frozen,
+    Denied,
 }
 #[cw_serde]
 pub struct ParseSinglePriceFeedResponse {
     pub price: Price,
+    /// Set if the requested feed was resolved to a replacement feed.
+    pub redirect: Option<PriceFeedRedirect>,
+    /// Set if the requested feed is an aggregate feed, to the components its price was
+    /// aggregated from.
+    pub aggregated_from: Option<Vec<PriceIdentifier>>,
+    /// `price` as decimals, if normalization was requested.
+    pub normalized_price: Option<NormalizedPrice>,
+}
+
+#[cw_serde]
+pub struct PriceFeedIdsResponse {
+    pub ids: Vec<PriceIdentifier>,
+}
+
+#[cw_serde]
+pub struct ResolvePriceFeedIdResponse {
+    pub id: PriceIdentifier,
+    /// The id that queries for `id` are served from. Equal to `id` if no redirect is set.
+    pub resolved_id: PriceIdentifier,
+}
+
+#[cw_serde]
+pub struct RegisteredPriceFeed {
+    pub id: PriceIdentifier,
+    pub metadata: PriceFeedMetadata,
+}
+
+#[cw_serde]
+pub struct PriceFeedRegistryResponse {
+    pub price_feeds: Vec<RegisteredPriceFeed>,
+}
+
+#[cw_serde]
+pub struct PriceBoundsResponse {
+    /// The id of the feed the price was taken from.
+    pub id: PriceIdentifier,
+    /// Set if the requested feed was resolved to a replacement feed.
+    pub redirect: Option<PriceFeedRedirect>,
+    pub price: Decimal256,
+    pub conf: Decimal256,
+    /// `price - conf_multiplier * conf`, floored at zero.
+    pub lower: Decimal256,
+    /// `price + conf_multiplier * conf`.
+    pub upper: Decimal256,
+    /// `conf` in basis points of `price`.
+    pub conf_bps: Decimal256,
+}
+
+#[cw_serde]
+pub enum EmaGuardMode {
+    /// Return the EMA price when the spot price is not trusted.
+    FallbackToEma,
+    /// Fail with `EmaGuardTriggered` when the spot price is not trusted.
+    Fail,
+}
+
+#[cw_serde]
+pub struct GuardedPriceResponse {
+    pub price: Price,
+    /// Whether `price` is the EMA price rather than the spot price.
+    pub used_ema: bool,
+    /// Set if the requested feed was resolved to a replacement feed.
+    pub redirect: Option<PriceFeedRedirect>,
+    /// Set if the requested feed is an aggregate feed, to the components its price was
+    /// aggregated from.
+    pub aggregated_from: Option<Vec<PriceIdentifier>>,
+}
+
+#[cw_serde]
+pub struct ValueOfResponse {
+    pub value: Decimal256,
+    /// Confidence interval of `value`, in the same units.
+    pub conf: Decimal256,
 }
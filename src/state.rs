@@ -1,7 +1,8 @@
 use {
     cosmwasm_schema::cw_serde,
-    cosmwasm_std::{Addr, Binary},
-    cw_storage_plus::Item,
+    cosmwasm_std::{Addr, Binary, Decimal256, Empty},
+    cw_storage_plus::{Item, Map},
+    pyth_sdk::PriceIdentifier,
     std::collections::HashSet,
 };
 
@@ -33,6 +34,106 @@ pub struct ConfigInfo {
     // Warning: This id needs to agree with the wormhole chain id.
     // We should read this directly from wormhole, but their contract doesn't expose it.
     pub chain_id: u16,
+    // Maximum number of seconds a price update may be published ahead of the block time.
+    // `None` disables the check.
+    pub max_publish_time_skew: Option<u64>,
+    // Maximum width of a price's confidence interval, in basis points of the price.
+    // `None` disables the check.
+    pub max_conf_bps: Option<u64>,
+    // Whether zero and negative prices are rejected. Off by default, since feeds that are not
+    // trading or have just been listed can legitimately publish a zero price.
+    pub reject_non_positive_prices: bool,
+    // Emergency switch set by governance. While paused, price updates are not parsed, but
+    // governance instructions are still executed.
+    pub paused: bool,
+    // Default number of confidence intervals that price bounds are placed away from the price.
+    pub conf_multiplier: Decimal256,
+    // Guarded price queries return the EMA price instead of the spot price when the spot
+    // confidence is wider than this, in basis points of the spot price. `None` disables the check.
+    pub ema_guard_max_conf_bps: Option<u64>,
+    // Guarded price queries return the EMA price instead of the spot price when the spot price
+    // deviates from the EMA price by more than this, in basis points of the EMA price. `None`
+    // disables the check.
+    pub ema_guard_max_deviation_bps: Option<u64>,
+}
+
+/// Descriptive metadata that governance registers for a price feed.
+#[cw_serde]
+pub struct PriceFeedMetadata {
+    /// Human readable symbol, e.g. "ATOM/USD". Unique across the registry.
+    pub symbol: String,
+    pub asset_class: String,
+    pub base: String,
+    pub quote: String,
+    /// The exponent prices for this feed are expected to be published with.
+    pub expo: i32,
+}
+
+/// The price feed that values a bank denom, and the number of decimals of that denom.
+#[cw_serde]
+pub struct DenomPriceFeed {
+    pub price_feed_id: PriceIdentifier,
+    pub decimals: u8,
+}
+
+/// A synthetic price feed that governance defines in terms of ordinary price feeds.
+#[cw_serde]
+pub enum DerivedPriceFeed {
+    /// The price of `base` in units of `quote`, e.g. ATOM/OSMO from ATOM/USD and OSMO/USD,
+    /// expressed with the exponent `expo`.
+    CrossRate {
+        base: PriceIdentifier,
+        quote: PriceIdentifier,
+        expo: i32,
+    },
+    /// The reciprocal of `feed`, e.g. USD/ATOM from ATOM/USD, expressed with the exponent `expo`.
+    Inverse { feed: PriceIdentifier, expo: i32 },
+    /// The weighted sum of `components`, e.g. an LST index or a stablecoin basket, expressed with
+    /// the exponent `expo`.
+    Basket {
+        components: Vec<BasketComponent>,
+        expo: i32,
+    },
+    /// The median of `components`, for assets with several feeds. Components published more
+    /// than `max_age` seconds before the block time, or deviating from the median of the fresh
+    /// components by more than `max_deviation_bps` basis points, are dropped. At least
+    /// `min_components` components must remain.
+    Median {
+        components: Vec<PriceIdentifier>,
+        max_age: u64,
+        max_deviation_bps: u64,
+        min_components: u8,
+    },
+}
+
+/// A price feed held in a [DerivedPriceFeed::Basket] with the weight
+/// `quantity * 10^quantity_expo`.
+#[cw_serde]
+pub struct BasketComponent {
+    pub id: PriceIdentifier,
+    pub quantity: i64,
+    pub quantity_expo: i32,
 }
 
 pub const CONFIG: Item<ConfigInfo> = Item::new("config");
+
+/// Price feeds temporarily frozen by governance, keyed by price feed id.
+pub const FROZEN_PRICE_FEEDS: Map<&[u8], Empty> = Map::new("frozen_price_feeds");
+
+/// Price feeds deny-listed by governance, keyed by price feed id.
+pub const DENIED_PRICE_FEEDS: Map<&[u8], Empty> = Map::new("denied_price_feeds");
+
+/// Replacement ids for deprecated price feeds, keyed by the deprecated price feed id.
+pub const PRICE_FEED_REDIRECTS: Map<&[u8], PriceIdentifier> = Map::new("price_feed_redirects");
+
+/// Registered price feed metadata, keyed by price feed id.
+pub const PRICE_FEED_METADATA: Map<&[u8], PriceFeedMetadata> = Map::new("price_feed_metadata");
+
+/// Registered price feed ids, keyed by symbol.
+pub const PRICE_FEED_SYMBOLS: Map<&str, PriceIdentifier> = Map::new("price_feed_symbols");
+
+/// Price feeds used to value bank denoms, keyed by denom.
+pub const DENOM_PRICE_FEEDS: Map<&str, DenomPriceFeed> = Map::new("denom_price_feeds");
+
+/// Derived price feed definitions, keyed by the id they are served under.
+pub const DERIVED_PRICE_FEEDS: Map<&[u8], DerivedPriceFeed> = Map::new("derived_price_feeds");